@@ -0,0 +1,90 @@
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+
+/// GitHub REST API base URL
+const GITHUB_API_URL: &str = "https://api.github.com";
+
+/// Milestones fetched per page, the maximum allowed by GitHub
+const MILESTONES_PER_PAGE: usize = 100;
+
+/// Milestone fields read from `GET /repos/{owner}/{repo}/milestones`
+#[derive(Debug, Deserialize)]
+struct Milestone {
+    number: u64,
+    title: String,
+}
+
+/// Looks up the number of the milestone titled `name`, going through every
+/// page of open and closed milestones
+pub(crate) async fn resolve_milestone(
+    repository_name: &str,
+    repository_owner: &str,
+    name: &str,
+    token: Option<&str>,
+) -> Result<u64> {
+    let client = reqwest::Client::new();
+    let url = format!(
+        "{}/repos/{}/{}/milestones",
+        GITHUB_API_URL, repository_owner, repository_name
+    );
+
+    for page in 1.. {
+        let mut request = client
+            .get(&url)
+            .header(reqwest::header::USER_AGENT, "github-issue-url")
+            .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+            .query(&[
+                ("state", String::from("all")),
+                ("per_page", MILESTONES_PER_PAGE.to_string()),
+                ("page", page.to_string()),
+            ]);
+
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+
+        let milestones: Vec<Milestone> = request
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| Error::ApiError(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| Error::ApiError(e.to_string()))?;
+
+        if let Some(number) = find_milestone(&milestones, name) {
+            return Ok(number);
+        }
+
+        if milestones.len() < MILESTONES_PER_PAGE {
+            break;
+        }
+    }
+
+    Err(Error::MilestoneNotFound(name.to_string()))
+}
+
+/// Number of the milestone titled `name`
+fn find_milestone(milestones: &[Milestone], name: &str) -> Option<u64> {
+    milestones
+        .iter()
+        .find(|milestone| milestone.title == name)
+        .map(|milestone| milestone.number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_milestone_by_title() {
+        let milestones: Vec<Milestone> = serde_json::from_str(
+            r#"[{"number": 1, "title": "v0.1.0", "state": "closed"}, {"number": 4, "title": "v0.2.0", "state": "open"}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(find_milestone(&milestones, "v0.2.0"), Some(4));
+        assert_eq!(find_milestone(&milestones, "v1.0.0"), None);
+    }
+}
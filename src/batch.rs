@@ -0,0 +1,146 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::error::Result;
+use crate::{RepoRef, SINGLE_VALUED_PARAMS};
+
+/// Builds many issue URLs for the same repository, such as one issue per
+/// failure read from a CSV file.
+///
+/// Each issue is described by its params as `(key, value)` pairs, set same as
+/// `Issue::custom_param`, except for single-valued params such as `title` or
+/// `body` which replace their previous value, same as `Issue::title` does.
+/// A single `Issue` is reused for every entry and each URL is written into a
+/// buffer sized after the previous URL, which is then handed out as is, so
+/// each entry allocates its URL once.
+///
+/// # Example
+///
+/// ```
+/// use github_issue_url::{BatchBuilder, RepoRef};
+///
+/// let repo = RepoRef::new("EstebanBorai", "github-issue-url").unwrap();
+/// let have = BatchBuilder::new(repo).build(&[
+///     vec![("title", "Null pointer"), ("labels", "bug")],
+///     vec![("", "Stack overflow")],
+/// ]);
+///
+/// assert_eq!(
+///     have[0].as_deref().unwrap(),
+///     "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null+pointer&labels=bug"
+/// );
+/// assert!(have[1].is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct BatchBuilder<'a> {
+    repo: RepoRef<'a>,
+}
+
+impl<'a> BatchBuilder<'a> {
+    pub fn new(repo: RepoRef<'a>) -> Self {
+        BatchBuilder { repo }
+    }
+
+    /// Builds the URL for each entry of `issues`, in order.
+    ///
+    /// Each URL is written into the buffer with `Issue::write_url` and moved
+    /// into the result. A failing entry results in the same error
+    /// `Issue::custom_param` or `Issue::write_url` returns, without affecting
+    /// the other entries.
+    pub fn build<'p, I, P>(&self, issues: I) -> Vec<Result<String>>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<[(&'p str, &'p str)]>,
+    {
+        let mut issue = self.repo.issue();
+        let mut buf = String::new();
+
+        issues
+            .into_iter()
+            .map(|params| {
+                issue.clear_params();
+
+                for &(key, value) in params.as_ref() {
+                    if SINGLE_VALUED_PARAMS.contains(&key) {
+                        issue.set_param(key, value);
+                    } else {
+                        issue.custom_param(key, value)?;
+                    }
+                }
+
+                issue.write_url(&mut buf)?;
+
+                let url = mem::take(&mut buf);
+
+                buf.reserve(url.len());
+
+                Ok(url)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn build_urls_for_each_entry() {
+        let mut repo = RepoRef::new("EstebanBorai", "github-issue-url").unwrap();
+
+        repo.with_base_url("https://github.mycorp.com").unwrap();
+
+        let have = BatchBuilder::new(repo).build(vec![
+            vec![("title", "Null pointer"), ("labels", "bug")],
+            vec![("", "Stack overflow")],
+            vec![("title", "Stack overflow")],
+        ]);
+
+        assert_eq!(
+            have[0].as_deref().unwrap(),
+            "https://github.mycorp.com/EstebanBorai/github-issue-url/issues/new?title=Null+pointer&labels=bug"
+        );
+        assert!(matches!(have[1], Err(Error::EmptyParamKey)));
+        assert_eq!(
+            have[2].as_deref().unwrap(),
+            "https://github.mycorp.com/EstebanBorai/github-issue-url/issues/new?title=Stack+overflow"
+        );
+    }
+
+    #[test]
+    fn build_urls_from_borrowed_params() {
+        let batch = BatchBuilder::new(RepoRef::new("EstebanBorai", "github-issue-url").unwrap());
+        let titles = [String::from("Null pointer"), String::from("Stack overflow")];
+        let issues: Vec<Vec<(&str, &str)>> = titles
+            .iter()
+            .map(|title| vec![("title", title.as_str())])
+            .collect();
+
+        let have = batch.build(&issues);
+
+        assert_eq!(
+            have[1].as_deref().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Stack+overflow"
+        );
+    }
+
+    #[test]
+    fn replace_single_valued_params() {
+        let batch = BatchBuilder::new(RepoRef::new("EstebanBorai", "github-issue-url").unwrap());
+        let have = batch.build([[
+            ("title", "Null"),
+            ("labels", "bug"),
+            ("body", "Null is a flag."),
+            ("title", "Null pointer"),
+            ("labels", "production"),
+            ("body", "Null is a flag, twice."),
+        ]]);
+
+        assert_eq!(
+            have[0].as_deref().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null+pointer&labels=bug&body=Null+is+a+flag%2C+twice.&labels=production"
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter;
+
+use url::Url;
+
+use crate::error::{Error, Result};
+use crate::{
+    parse_base_url, parsed_url_with_params, parsed_url_with_path, url_with_params, url_with_path,
+};
+
+/// Base URL used when no custom base URL is provided through
+/// `BitbucketIssue::with_base_url`
+const BITBUCKET_BASE_URL: &str = "https://bitbucket.org";
+
+/// Bitbucket issue kind, set with `BitbucketIssue::kind`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    Bug,
+    Enhancement,
+    Proposal,
+    Task,
+}
+
+impl Kind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Kind::Bug => "bug",
+            Kind::Enhancement => "enhancement",
+            Kind::Proposal => "proposal",
+            Kind::Task => "task",
+        }
+    }
+}
+
+/// Bitbucket issue priority, set with `BitbucketIssue::priority`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Priority {
+    Trivial,
+    Minor,
+    Major,
+    Critical,
+    Blocker,
+}
+
+impl Priority {
+    fn as_str(self) -> &'static str {
+        match self {
+            Priority::Trivial => "trivial",
+            Priority::Minor => "minor",
+            Priority::Major => "major",
+            Priority::Critical => "critical",
+            Priority::Blocker => "blocker",
+        }
+    }
+}
+
+/// Bitbucket issue struct with support for prefilling a new Bitbucket issue.
+///
+/// Same as `Issue` but targets the Bitbucket "Create Issue" URL
+/// (`https://bitbucket.org/<workspace>/<repository>/issues/new`) and uses
+/// Bitbucket param names, such as `content` for the body.
+///
+/// Requires the `bitbucket` feature.
+///
+/// # Example
+///
+/// ```
+/// use github_issue_url::bitbucket::{BitbucketIssue, Kind, Priority};
+///
+/// let mut have = BitbucketIssue::new("github-issue-url", "EstebanBorai").unwrap();
+///
+/// have.title("Null: The Billion Dollar Mistake");
+/// have.body("Null is a flag.");
+/// have.kind(Kind::Bug);
+/// have.priority(Priority::Major);
+///
+/// let have = have.url().unwrap();
+///
+/// assert_eq!(have, "https://bitbucket.org/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake&content=Null+is+a+flag.&kind=bug&priority=major");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitbucketIssue<'a> {
+    repository_name: &'a str,
+    workspace: &'a str,
+    params: Vec<(&'a str, Cow<'a, str>)>,
+    base_url: Option<Url>,
+}
+
+impl<'a> BitbucketIssue<'a> {
+    /// Creates a `BitbucketIssue` for the provided repository.
+    ///
+    /// Returns `Error::EmptyRepositoryName` or `Error::EmptyRepositoryOwner`
+    /// if the repository name or the workspace are empty.
+    pub fn new(repository_name: &'a str, workspace: &'a str) -> Result<Self> {
+        if repository_name.is_empty() {
+            return Err(Error::EmptyRepositoryName);
+        }
+
+        if workspace.is_empty() {
+            return Err(Error::EmptyRepositoryOwner);
+        }
+
+        Ok(BitbucketIssue {
+            repository_name,
+            workspace,
+            params: Vec::new(),
+            base_url: None,
+        })
+    }
+
+    /// Prefilled issue content, replacing any content previously set
+    pub fn body(&mut self, body: &'a str) {
+        self.set_param("content", body);
+    }
+
+    /// Kind of issue, such as `Kind::Bug`
+    pub fn kind(&mut self, kind: Kind) {
+        self.set_param("kind", kind.as_str());
+    }
+
+    /// Priority of the issue, such as `Priority::Major`
+    pub fn priority(&mut self, priority: Priority) {
+        self.set_param("priority", priority.as_str());
+    }
+
+    /// Prefilled issue title, replacing any title previously set
+    pub fn title(&mut self, title: &'a str) {
+        self.set_param("title", title);
+    }
+
+    /// Host to build the issue URL for, useful when targeting a Bitbucket
+    /// Server instance. Refer to `Issue::with_base_url`.
+    ///
+    /// When not provided, `https://bitbucket.org` is used.
+    pub fn with_base_url(&mut self, base_url: &str) -> Result<()> {
+        self.base_url = Some(parse_base_url(base_url)?);
+
+        Ok(())
+    }
+
+    #[must_use = "building the URL has no effect unless it is used"]
+    pub fn url(&self) -> Result<String> {
+        Ok(url_with_params(self.repository_url(), &self.params))
+    }
+
+    /// Builds the issue URL as a `url::Url`. Refer to `Issue::url_parsed`.
+    pub fn url_parsed(&self) -> Result<Url> {
+        Ok(parsed_url_with_params(
+            parsed_url_with_path(self.base_url.as_ref(), BITBUCKET_BASE_URL, self.segments()),
+            &self.params,
+        ))
+    }
+
+    fn set_param(&mut self, key: &'a str, value: &'a str) {
+        match self.params.iter_mut().find(|(param, _)| *param == key) {
+            Some((_, current)) => *current = value.into(),
+            None => self.params.push((key, value.into())),
+        }
+    }
+
+    /// The "Create Issue" URL without query params
+    fn repository_url(&self) -> String {
+        url_with_path(self.base_url.as_ref(), BITBUCKET_BASE_URL, self.segments())
+    }
+
+    /// Path segments of the "Create Issue" URL
+    fn segments(&self) -> impl Iterator<Item = &str> {
+        iter::once(self.workspace)
+            .chain(iter::once(self.repository_name))
+            .chain(["issues", "new"].iter().copied())
+    }
+}
+
+/// Writes the same URL returned by `BitbucketIssue::url`, falling back to
+/// the "Create Issue" URL without query params if building the URL fails.
+impl<'a> fmt::Display for BitbucketIssue<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.url() {
+            Ok(url) => write!(f, "{}", url),
+            Err(_) => write!(f, "{}", self.repository_url()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_bitbucket_issue_url() {
+        let mut have = BitbucketIssue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.with_base_url("https://bitbucket.mycorp.com").unwrap();
+        have.kind(Kind::Task);
+        have.title("Support Bitbucket");
+        have.kind(Kind::Enhancement);
+        have.priority(Priority::Minor);
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://bitbucket.mycorp.com/EstebanBorai/github-issue-url/issues/new?kind=enhancement&title=Support+Bitbucket&priority=minor"
+        );
+        assert_eq!(have.url_parsed().unwrap().as_str(), have.url().unwrap());
+    }
+
+    #[test]
+    fn return_error_if_repository_is_invalid() {
+        assert!(matches!(
+            BitbucketIssue::new("", "EstebanBorai"),
+            Err(Error::EmptyRepositoryName)
+        ));
+        assert!(matches!(
+            BitbucketIssue::new("github-issue-url", ""),
+            Err(Error::EmptyRepositoryOwner)
+        ));
+    }
+}
@@ -0,0 +1,304 @@
+use alloc::borrow::Cow;
+
+use crate::error::{Error, Result};
+use crate::{Issue, Reference, Template};
+
+/// Consuming builder for `Issue`.
+///
+/// Every method takes the builder by value and returns it back, so an `Issue`
+/// can be built in a single expression. Methods are named after the `Issue`
+/// setters they call, which keeps `Issue` a plain set of mutable setters.
+///
+/// # Example
+///
+/// ```
+/// use github_issue_url::Issue;
+///
+/// let have = Issue::builder("github-issue-url", "EstebanBorai")
+///     .title("Null: The Billion Dollar Mistake")
+///     .labels("bug,production,high-severity")
+///     .build()
+///     .unwrap()
+///     .url()
+///     .unwrap();
+///
+/// assert_eq!(have, "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake&labels=bug%2Cproduction%2Chigh-severity");
+/// ```
+///
+/// Dropping the builder returned by any method is reported by the
+/// `unused_must_use` lint, as the changes are lost along with it:
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+///
+/// use github_issue_url::Issue;
+///
+/// let builder = Issue::builder("github-issue-url", "EstebanBorai");
+///
+/// builder.title("Null: The Billion Dollar Mistake");
+/// ```
+#[derive(Debug)]
+#[must_use = "builder methods return the updated builder, which must be used to build the issue"]
+pub struct IssueBuilder<'a> {
+    issue: Issue<'a>,
+    /// First error returned by a fallible setter, surfaced by
+    /// `IssueBuilder::build`
+    error: Option<Error>,
+}
+
+impl<'a> IssueBuilder<'a> {
+    pub(crate) fn new(repository_name: &'a str, repository_owner: &'a str) -> Self {
+        IssueBuilder {
+            issue: Issue::new_unchecked(repository_name, repository_owner),
+            error: None,
+        }
+    }
+
+    /// The username of the issue's assignee. Refer to `Issue::assignee`.
+    pub fn assignee(mut self, assignee: impl Into<Cow<'a, str>>) -> Self {
+        self.issue.assignee(assignee);
+        self
+    }
+
+    /// Assigns the issue to whoever opens it. Refer to
+    /// `Issue::assign_to_self`.
+    pub fn assign_to_self(mut self) -> Self {
+        self.issue.assign_to_self();
+        self
+    }
+
+    /// The usernames of the issue's assignees. Refer to `Issue::assignees`.
+    pub fn assignees(mut self, assignees: &[&'a str]) -> Self {
+        self.issue.assignees(assignees);
+        self
+    }
+
+    /// Prefilled issue body content. Refer to `Issue::body`.
+    pub fn body(mut self, body: impl Into<Cow<'a, str>>) -> Self {
+        self.issue.body(body);
+        self
+    }
+
+    /// Appends content to the body. Refer to `Issue::append_body`.
+    pub fn append_body(mut self, body: impl Into<Cow<'a, str>>) -> Self {
+        self.issue.append_body(body);
+        self
+    }
+
+    /// Renders `facts` as a markdown list and appends it to the body. Refer
+    /// to `Issue::body_from_facts`.
+    pub fn body_from_facts(mut self, facts: &[(&str, &str)]) -> Self {
+        self.issue.body_from_facts(facts);
+        self
+    }
+
+    /// Arbitrary query param. Refer to `Issue::custom_param`.
+    ///
+    /// If the key is empty, the error is returned by `IssueBuilder::build`.
+    pub fn custom_param(mut self, key: &'a str, value: impl Into<Cow<'a, str>>) -> Self {
+        let result = self.issue.custom_param(key, value);
+
+        self.catch(result)
+    }
+
+    /// Pushes a new param, keeping any param previously set with the same
+    /// key. Refer to `Issue::append_param`.
+    ///
+    /// If the key is empty, the error is returned by `IssueBuilder::build`.
+    pub fn append_param(mut self, key: &'a str, value: impl Into<Cow<'a, str>>) -> Self {
+        let result = self.issue.append_param(key, value);
+
+        self.catch(result)
+    }
+
+    /// Prefills the fields of a YAML issue form. Refer to
+    /// `Issue::form_fields`.
+    ///
+    /// If any field `id` is empty, the error is returned by
+    /// `IssueBuilder::build`.
+    pub fn form_fields(mut self, template: &'a str, fields: &[(&'a str, &'a str)]) -> Self {
+        let result = self.issue.form_fields(template, fields);
+
+        self.catch(result)
+    }
+
+    /// Issue labels separated by comma (`,`). Refer to `Issue::labels`.
+    pub fn labels(mut self, labels: impl Into<Cow<'a, str>>) -> Self {
+        self.issue.labels(labels);
+        self
+    }
+
+    /// Issue labels provided as a slice. Refer to `Issue::labels_slice`.
+    ///
+    /// If labels are invalid, the error is returned by `IssueBuilder::build`.
+    pub fn labels_slice(mut self, labels: &[&'a str]) -> Self {
+        let result = self.issue.labels_slice(labels);
+
+        self.catch(result)
+    }
+
+    /// Host to build the issue URL for. Refer to `Issue::with_base_url`.
+    ///
+    /// If the base URL is invalid, the error is returned by
+    /// `IssueBuilder::build`.
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        let result = self.issue.with_base_url(base_url);
+
+        self.catch(result)
+    }
+
+    /// The ID (number) of the milestone. Refer to `Issue::milestone`.
+    pub fn milestone(mut self, milestone: impl Into<Cow<'a, str>>) -> Self {
+        self.issue.milestone(milestone);
+        self
+    }
+
+    /// The ID (number) of the milestone. Refer to `Issue::milestone_id`.
+    pub fn milestone_id(mut self, milestone: u64) -> Self {
+        self.issue.milestone_id(milestone);
+        self
+    }
+
+    /// The IDs (number) of the projects. Refer to `Issue::projects`.
+    pub fn projects(mut self, projects: impl Into<Cow<'a, str>>) -> Self {
+        self.issue.projects(projects);
+        self
+    }
+
+    /// The IDs (number) of the projects provided as a slice. Refer to
+    /// `Issue::projects_slice`.
+    pub fn projects_slice(mut self, projects: &[u64]) -> Self {
+        self.issue.projects_slice(projects);
+        self
+    }
+
+    /// Adds the issue to a project of the current Projects experience. Refer
+    /// to `Issue::project_v2`.
+    pub fn project_v2(mut self, owner: &str, number: u64) -> Self {
+        self.issue.project_v2(owner, number);
+        self
+    }
+
+    /// Footer appended to the body when building the URL. Refer to
+    /// `Issue::with_footer`.
+    pub fn footer(mut self, footer: impl Into<Cow<'a, str>>) -> Self {
+        self.issue.with_footer(footer);
+        self
+    }
+
+    /// Path within the repository to append the query params to. Refer to
+    /// `Issue::path`.
+    pub fn path(mut self, path: impl Into<Cow<'a, str>>) -> Self {
+        self.issue.path(path);
+        self
+    }
+
+    /// References another issue at the start of the body. Refer to
+    /// `Issue::reference_issue`.
+    pub fn reference_issue(mut self, keyword: Reference, number: u64) -> Self {
+        self.issue.reference_issue(keyword, number);
+        self
+    }
+
+    /// Prefilled issue title. Refer to `Issue::title`.
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.issue.title(title);
+        self
+    }
+
+    /// Prefilled issue title, shortened to at most `max` chars. Refer to
+    /// `Issue::title_truncated`.
+    pub fn title_truncated(mut self, title: &'a str, max: usize) -> Self {
+        self.issue.title_truncated(title, max);
+        self
+    }
+
+    /// The name of the issue template. Refer to `Issue::template`.
+    pub fn template(mut self, template: impl Into<Cow<'a, str>>) -> Self {
+        self.issue.template(template);
+        self
+    }
+
+    /// The issue template to use. Refer to `Issue::template_kind`.
+    pub fn template_kind(mut self, template: Template<'a>) -> Self {
+        self.issue.template_kind(template);
+        self
+    }
+
+    /// Validates the repository name and owner and returns the `Issue`.
+    ///
+    /// Fails with the same errors as `Issue::new`, or with the first error
+    /// returned by a fallible setter such as `IssueBuilder::labels_slice`.
+    pub fn build(self) -> Result<Issue<'a>> {
+        self.issue.repo.validate()?;
+
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        Ok(self.issue)
+    }
+
+    /// Keeps the first error returned by a fallible setter
+    fn catch(mut self, result: Result<()>) -> Self {
+        if let Err(error) = result {
+            self.error.get_or_insert(error);
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_same_issue_as_setters() {
+        let mut want = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        want.title("Null: The Billion Dollar Mistake");
+        want.body("Null is a flag.");
+        want.template("bug_report.md");
+        want.labels("bug,production,high-severity");
+        want.assignee("EstebanBorai");
+        want.milestone("1");
+        want.projects("1");
+
+        let have = Issue::builder("github-issue-url", "EstebanBorai")
+            .title("Null: The Billion Dollar Mistake")
+            .body("Null is a flag.")
+            .template("bug_report.md")
+            .labels("bug,production,high-severity")
+            .assignee("EstebanBorai")
+            .milestone("1")
+            .projects("1")
+            .build()
+            .unwrap();
+
+        assert_eq!(have, want);
+    }
+
+    #[test]
+    fn build_returns_validation_errors() {
+        let have = Issue::builder("", "EstebanBorai").build();
+
+        assert!(matches!(have, Err(Error::EmptyRepositoryName)));
+
+        let have = Issue::builder("github-issue-url", "").build();
+
+        assert!(matches!(have, Err(Error::EmptyRepositoryOwner)));
+
+        let have = Issue::builder("github-issue-url", "EstebanBorai")
+            .base_url("ftp://github.mycorp.com")
+            .build();
+
+        assert!(matches!(have, Err(Error::InvalidBaseUrl(_))));
+
+        let have = Issue::builder("github-issue-url", "EstebanBorai")
+            .labels_slice(&["bug", "production,high-severity"])
+            .build();
+
+        assert!(matches!(have, Err(Error::InvalidLabel(_))));
+    }
+}
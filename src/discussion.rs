@@ -0,0 +1,163 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use url::Url;
+
+use crate::error::Result;
+use crate::{parsed_url_with_params, url_with_params, RepoRef};
+
+/// GitHub discussion struct with support for prefilling a new discussion.
+///
+/// Same as `Issue` but targets the "New Discussion" URL
+/// (`https://github.com/<owner>/<repository>/discussions/new`).
+///
+/// # Example
+///
+/// ```
+/// use github_issue_url::Discussion;
+///
+/// let mut have = Discussion::new("github-issue-url", "EstebanBorai").unwrap();
+///
+/// have.category("ideas");
+/// have.title("Support GitLab");
+/// have.body("Would be great to prefill GitLab issues too.");
+///
+/// let have = have.url().unwrap();
+///
+/// assert_eq!(have, "https://github.com/EstebanBorai/github-issue-url/discussions/new?category=ideas&title=Support+GitLab&body=Would+be+great+to+prefill+GitLab+issues+too.");
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct Discussion<'a> {
+    repo: RepoRef<'a>,
+    params: Vec<(&'a str, Cow<'a, str>)>,
+}
+
+impl<'a> Discussion<'a> {
+    pub fn new(repository_name: &'a str, repository_owner: &'a str) -> Result<Self> {
+        Ok(Discussion::from_repo(RepoRef::new(
+            repository_owner,
+            repository_name,
+        )?))
+    }
+
+    /// Creates a `Discussion` for `repo`. Refer to `RepoRef::discussion`.
+    pub(crate) fn from_repo(repo: RepoRef<'a>) -> Self {
+        Discussion {
+            repo,
+            params: Vec::new(),
+        }
+    }
+
+    /// Prefilled discussion body content, replacing any body previously set
+    pub fn body(&mut self, body: &'a str) {
+        self.set_param("body", body);
+    }
+
+    /// The slug of the discussion category, as seen in the category URL,
+    /// replacing any category previously set.
+    ///
+    /// https://github.com/<owner>/<repository>/discussions/categories/<category slug>
+    pub fn category(&mut self, category: &'a str) {
+        self.set_param("category", category);
+    }
+
+    /// Prefilled discussion title, replacing any title previously set
+    pub fn title(&mut self, title: &'a str) {
+        self.set_param("title", title);
+    }
+
+    /// Host to build the discussion URL for. Refer to `Issue::with_base_url`.
+    pub fn with_base_url(&mut self, base_url: &str) -> Result<()> {
+        self.repo.with_base_url(base_url)
+    }
+
+    #[must_use = "building the URL has no effect unless it is used"]
+    pub fn url(&self) -> Result<String> {
+        Ok(url_with_params(self.repository_url(), &self.params))
+    }
+
+    /// Builds the discussion URL as a `url::Url`. Refer to
+    /// `Issue::url_parsed`.
+    pub fn url_parsed(&self) -> Result<Url> {
+        Ok(parsed_url_with_params(
+            self.repo.parsed_discussion_url(),
+            &self.params,
+        ))
+    }
+
+    /// The "New Discussion" URL without query params
+    fn repository_url(&self) -> String {
+        self.repo.new_discussion_url()
+    }
+
+    fn set_param(&mut self, key: &'a str, value: &'a str) {
+        match self.params.iter_mut().find(|(param, _)| *param == key) {
+            Some((_, current)) => *current = value.into(),
+            None => self.params.push((key, value.into())),
+        }
+    }
+}
+
+/// Writes the same URL returned by `Discussion::url`, falling back to the
+/// "New Discussion" URL without query params if building the URL fails.
+impl<'a> fmt::Display for Discussion<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.url() {
+            Ok(url) => write!(f, "{}", url),
+            Err(_) => write!(f, "{}", self.repository_url()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn build_discussion_url() {
+        let mut have = Discussion::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.with_base_url("https://github.mycorp.com").unwrap();
+        have.category("q-a");
+        have.title("How to prefill issue forms?");
+
+        let have = have.url().unwrap();
+
+        assert_eq!(
+            have,
+            "https://github.mycorp.com/EstebanBorai/github-issue-url/discussions/new?category=q-a&title=How+to+prefill+issue+forms%3F"
+        );
+    }
+
+    #[test]
+    fn replace_title_body_and_category() {
+        let mut have = Discussion::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Support GitLab");
+        have.body("Would be great.");
+        have.category("general");
+        have.title("Support Bitbucket");
+        have.body("Would be great too.");
+        have.category("ideas");
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/discussions/new?title=Support+Bitbucket&body=Would+be+great+too.&category=ideas"
+        );
+    }
+
+    #[test]
+    fn return_error_if_repository_is_invalid() {
+        assert!(matches!(
+            Discussion::new("", "EstebanBorai"),
+            Err(Error::EmptyRepositoryName)
+        ));
+        assert!(matches!(
+            Discussion::new("github-issue-url", ""),
+            Err(Error::EmptyRepositoryOwner)
+        ));
+    }
+}
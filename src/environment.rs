@@ -0,0 +1,35 @@
+use std::env::consts::ARCH;
+
+/// Markdown section describing the host system, optionally including the
+/// application `version`
+pub(crate) fn system_info(version: Option<&str>) -> String {
+    let info = os_info::get();
+    let mut section = format!(
+        "### System Information\n\n- OS: {}\n- OS Version: {}\n- Architecture: {}",
+        info.os_type(),
+        info.version(),
+        ARCH
+    );
+
+    if let Some(version) = version {
+        section.push_str("\n- Version: ");
+        section.push_str(version);
+    }
+
+    section
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_host_system() {
+        let have = system_info(Some("1.2.3"));
+
+        assert!(have.starts_with("### System Information\n\n- OS: "));
+        assert!(have.contains(&format!("\n- Architecture: {}\n", ARCH)));
+        assert!(have.ends_with("\n- Version: 1.2.3"));
+        assert!(!system_info(None).contains("- Version:"));
+    }
+}
@@ -1,6 +1,8 @@
+use alloc::string::String;
+
 use thiserror::Error as ThisError;
 
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 #[derive(ThisError, Debug)]
 pub enum Error {
@@ -8,6 +10,189 @@ pub enum Error {
     EmptyRepositoryOwner,
     #[error("Repository name is not defined")]
     EmptyRepositoryName,
+    #[error("Repository owner name \"{0}\" is not a valid GitHub username or organization name")]
+    InvalidRepositoryOwner(String),
+    #[error("Repository name \"{0}\" is not a valid GitHub repository name")]
+    InvalidRepositoryName(String),
     #[error("Failed to parse URL with provided params. {0}")]
-    UrlParseError(String),
+    UrlParseError(#[from] url::ParseError),
+    #[error("Invalid base URL. {0}")]
+    InvalidBaseUrl(String),
+    #[error("URL is not a \"New Issue\" URL")]
+    NotAnIssueUrl,
+    #[error("Label \"{0}\" contains the labels separator \",\"")]
+    InvalidLabel(String),
+    #[error("Query param key is empty")]
+    EmptyParamKey,
+    #[error("Failed to open URL in the browser. {0}")]
+    BrowserLaunchFailed(String),
+    #[error("URL length of {length} bytes exceeds the limit of {limit} bytes")]
+    UrlTooLong { length: usize, limit: usize },
+    #[error("Failed to read git repository. {0}")]
+    GitError(String),
+    #[error("Remote URL \"{0}\" is not a recognized GitHub repository URL")]
+    RemoteParseFailed(String),
+    #[error("Slug \"{0}\" doesn't match <owner>/<repository>")]
+    InvalidSlug(String),
+    #[error("Branch name is not defined")]
+    EmptyBranchName,
+    #[error("Template variable \"{0}\" has no value")]
+    MissingTemplateVariable(String),
+    #[error("Query param \"{0}\" is only used by issue forms, but no template is set")]
+    UnknownParam(String),
+    #[error("Query param \"{0}\" is set more than once")]
+    DuplicateParam(String),
+    #[error("Title length of {length} chars exceeds the limit of {limit} chars")]
+    TitleTooLong { length: usize, limit: usize },
+    #[error("Query param \"{0}\" requires write access to the repository")]
+    RequiresWriteAccess(String),
+    #[error("GitHub API request failed. {0}")]
+    ApiError(String),
+    #[error("Milestone \"{0}\" not found")]
+    MilestoneNotFound(String),
+    #[error("Failed to read file. {0}")]
+    Io(String),
+    #[error("Environment variable \"{0}\" is not set")]
+    MissingEnvVar(String),
+    #[error("Issue template \"{0}\" not found")]
+    TemplateNotFound(String),
+    #[error("Table row {row} has {columns} cells, but the table has {expected} columns")]
+    TableShapeMismatch {
+        row: usize,
+        columns: usize,
+        expected: usize,
+    },
+    #[error("Failed to parse Cargo manifest. {0}")]
+    ManifestParse(String),
+    #[error(
+        "Triage label \"{0}\" has an empty prefix or value, or contains the labels separator \",\""
+    )]
+    InvalidTriageLabel(String),
+}
+
+impl Error {
+    /// Short identifier of the error variant, such as `"empty_repo_name"`,
+    /// to group errors by in telemetry.
+    ///
+    /// Unlike the `Display` message, codes are stable across versions.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::EmptyRepositoryOwner => "empty_repo_owner",
+            Error::EmptyRepositoryName => "empty_repo_name",
+            Error::InvalidRepositoryOwner(_) => "invalid_repo_owner",
+            Error::InvalidRepositoryName(_) => "invalid_repo_name",
+            Error::UrlParseError(_) => "url_parse",
+            Error::InvalidBaseUrl(_) => "invalid_base_url",
+            Error::NotAnIssueUrl => "not_an_issue_url",
+            Error::InvalidLabel(_) => "invalid_label",
+            Error::EmptyParamKey => "empty_param_key",
+            Error::BrowserLaunchFailed(_) => "browser_launch_failed",
+            Error::UrlTooLong { .. } => "url_too_long",
+            Error::GitError(_) => "git",
+            Error::RemoteParseFailed(_) => "remote_parse_failed",
+            Error::InvalidSlug(_) => "invalid_slug",
+            Error::EmptyBranchName => "empty_branch_name",
+            Error::MissingTemplateVariable(_) => "missing_template_variable",
+            Error::UnknownParam(_) => "unknown_param",
+            Error::DuplicateParam(_) => "duplicate_param",
+            Error::TitleTooLong { .. } => "title_too_long",
+            Error::RequiresWriteAccess(_) => "requires_write_access",
+            Error::ApiError(_) => "api",
+            Error::MilestoneNotFound(_) => "milestone_not_found",
+            Error::Io(_) => "io",
+            Error::MissingEnvVar(_) => "missing_env_var",
+            Error::TemplateNotFound(_) => "template_not_found",
+            Error::TableShapeMismatch { .. } => "table_shape_mismatch",
+            Error::ManifestParse(_) => "manifest_parse",
+            Error::InvalidTriageLabel(_) => "invalid_triage_label",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeSet;
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn each_variant_has_a_unique_stable_code() {
+        let errors = [
+            Error::EmptyRepositoryOwner,
+            Error::EmptyRepositoryName,
+            Error::InvalidRepositoryOwner(String::new()),
+            Error::InvalidRepositoryName(String::new()),
+            Error::UrlParseError(url::ParseError::EmptyHost),
+            Error::InvalidBaseUrl(String::new()),
+            Error::NotAnIssueUrl,
+            Error::InvalidLabel(String::new()),
+            Error::EmptyParamKey,
+            Error::BrowserLaunchFailed(String::new()),
+            Error::UrlTooLong {
+                length: 0,
+                limit: 0,
+            },
+            Error::GitError(String::new()),
+            Error::RemoteParseFailed(String::new()),
+            Error::InvalidSlug(String::new()),
+            Error::EmptyBranchName,
+            Error::MissingTemplateVariable(String::new()),
+            Error::UnknownParam(String::new()),
+            Error::DuplicateParam(String::new()),
+            Error::TitleTooLong {
+                length: 0,
+                limit: 0,
+            },
+            Error::RequiresWriteAccess(String::new()),
+            Error::ApiError(String::new()),
+            Error::MilestoneNotFound(String::new()),
+            Error::Io(String::new()),
+            Error::MissingEnvVar(String::new()),
+            Error::TemplateNotFound(String::new()),
+            Error::TableShapeMismatch {
+                row: 0,
+                columns: 0,
+                expected: 0,
+            },
+            Error::ManifestParse(String::new()),
+            Error::InvalidTriageLabel(String::new()),
+        ];
+        let codes: Vec<&str> = errors.iter().map(Error::code).collect();
+
+        assert_eq!(
+            codes,
+            [
+                "empty_repo_owner",
+                "empty_repo_name",
+                "invalid_repo_owner",
+                "invalid_repo_name",
+                "url_parse",
+                "invalid_base_url",
+                "not_an_issue_url",
+                "invalid_label",
+                "empty_param_key",
+                "browser_launch_failed",
+                "url_too_long",
+                "git",
+                "remote_parse_failed",
+                "invalid_slug",
+                "empty_branch_name",
+                "missing_template_variable",
+                "unknown_param",
+                "duplicate_param",
+                "title_too_long",
+                "requires_write_access",
+                "api",
+                "milestone_not_found",
+                "io",
+                "missing_env_var",
+                "template_not_found",
+                "table_shape_mismatch",
+                "manifest_parse",
+                "invalid_triage_label",
+            ]
+        );
+        assert_eq!(codes.iter().collect::<BTreeSet<_>>().len(), codes.len());
+    }
 }
@@ -0,0 +1,181 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter;
+
+use url::Url;
+
+use crate::error::{Error, Result};
+use crate::{
+    parse_base_url, parsed_url_with_params, parsed_url_with_path, url_with_params, url_with_path,
+};
+
+/// Base URL used when no custom base URL is provided through
+/// `GitLabIssue::with_base_url`
+const GITLAB_BASE_URL: &str = "https://gitlab.com";
+
+/// GitLab issue struct with support for prefilling a new GitLab issue.
+///
+/// Same as `Issue` but targets the GitLab "New Issue" URL
+/// (`https://gitlab.com/<namespace>/<project>/-/issues/new`) and uses GitLab
+/// param names, such as `issue[title]` and `issue[description]`.
+///
+/// # Example
+///
+/// ```
+/// use github_issue_url::gitlab::GitLabIssue;
+///
+/// let mut have = GitLabIssue::new("github-issue-url", "EstebanBorai").unwrap();
+///
+/// have.title("Null: The Billion Dollar Mistake");
+/// have.body("Null is a flag.");
+///
+/// let have = have.url().unwrap();
+///
+/// assert_eq!(have, "https://gitlab.com/EstebanBorai/github-issue-url/-/issues/new?issue%5Btitle%5D=Null%3A+The+Billion+Dollar+Mistake&issue%5Bdescription%5D=Null+is+a+flag.");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GitLabIssue<'a> {
+    project_name: &'a str,
+    namespace: &'a str,
+    params: Vec<(&'a str, Cow<'a, str>)>,
+    base_url: Option<Url>,
+}
+
+impl<'a> GitLabIssue<'a> {
+    /// Creates a `GitLabIssue` for the provided project.
+    ///
+    /// The namespace is either a username or a group, including subgroups
+    /// separated by a slash (`/`) such as `group/subgroup`.
+    ///
+    /// Returns `Error::EmptyRepositoryName` or `Error::EmptyRepositoryOwner`
+    /// if the project name or the namespace are empty.
+    pub fn new(project_name: &'a str, namespace: &'a str) -> Result<Self> {
+        if project_name.is_empty() {
+            return Err(Error::EmptyRepositoryName);
+        }
+
+        if namespace.is_empty() {
+            return Err(Error::EmptyRepositoryOwner);
+        }
+
+        Ok(GitLabIssue {
+            project_name,
+            namespace,
+            params: Vec::new(),
+            base_url: None,
+        })
+    }
+
+    /// Prefilled issue description, replacing any description previously
+    /// set
+    pub fn body(&mut self, body: &'a str) {
+        self.set_param("issue[description]", body);
+    }
+
+    /// Marks the issue as confidential
+    pub fn confidential(&mut self) {
+        self.set_param("issue[confidential]", "true");
+    }
+
+    /// The name of the description template to use, as found in
+    /// `.gitlab/issue_templates/<template name>.md`
+    pub fn template(&mut self, template: &'a str) {
+        self.set_param("issuable_template", template);
+    }
+
+    /// Prefilled issue title, replacing any title previously set
+    pub fn title(&mut self, title: &'a str) {
+        self.set_param("issue[title]", title);
+    }
+
+    /// Host to build the issue URL for, useful when targeting a self-hosted
+    /// GitLab instance. Refer to `Issue::with_base_url`.
+    ///
+    /// When not provided, `https://gitlab.com` is used.
+    pub fn with_base_url(&mut self, base_url: &str) -> Result<()> {
+        self.base_url = Some(parse_base_url(base_url)?);
+
+        Ok(())
+    }
+
+    #[must_use = "building the URL has no effect unless it is used"]
+    pub fn url(&self) -> Result<String> {
+        Ok(url_with_params(self.project_url(), &self.params))
+    }
+
+    /// Builds the issue URL as a `url::Url`. Refer to `Issue::url_parsed`.
+    pub fn url_parsed(&self) -> Result<Url> {
+        Ok(parsed_url_with_params(
+            parsed_url_with_path(self.base_url.as_ref(), GITLAB_BASE_URL, self.segments()),
+            &self.params,
+        ))
+    }
+
+    fn set_param(&mut self, key: &'a str, value: &'a str) {
+        match self.params.iter_mut().find(|(param, _)| *param == key) {
+            Some((_, current)) => *current = value.into(),
+            None => self.params.push((key, value.into())),
+        }
+    }
+
+    /// The "New Issue" URL without query params, with each segment of the
+    /// namespace and the project name percent-encoded
+    fn project_url(&self) -> String {
+        url_with_path(self.base_url.as_ref(), GITLAB_BASE_URL, self.segments())
+    }
+
+    /// Path segments of the "New Issue" URL
+    fn segments(&self) -> impl Iterator<Item = &str> {
+        self.namespace
+            .split('/')
+            .chain(iter::once(self.project_name))
+            .chain(["-", "issues", "new"].iter().copied())
+    }
+}
+
+/// Writes the same URL returned by `GitLabIssue::url`, falling back to the
+/// "New Issue" URL without query params if building the URL fails.
+impl<'a> fmt::Display for GitLabIssue<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.url() {
+            Ok(url) => write!(f, "{}", url),
+            Err(_) => write!(f, "{}", self.project_url()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_gitlab_issue_url() {
+        let mut have = GitLabIssue::new("github-issue-url", "group/subgroup").unwrap();
+
+        have.with_base_url("https://gitlab.mycorp.com").unwrap();
+        have.title("Null");
+        have.template("bug");
+        have.confidential();
+        have.title("Null: The Billion Dollar Mistake");
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://gitlab.mycorp.com/group/subgroup/github-issue-url/-/issues/new?issue%5Btitle%5D=Null%3A+The+Billion+Dollar+Mistake&issuable_template=bug&issue%5Bconfidential%5D=true"
+        );
+        assert_eq!(have.url_parsed().unwrap().as_str(), have.url().unwrap());
+    }
+
+    #[test]
+    fn return_error_if_project_is_invalid() {
+        assert!(matches!(
+            GitLabIssue::new("", "EstebanBorai"),
+            Err(Error::EmptyRepositoryName)
+        ));
+        assert!(matches!(
+            GitLabIssue::new("github-issue-url", ""),
+            Err(Error::EmptyRepositoryOwner)
+        ));
+    }
+}
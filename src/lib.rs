@@ -31,17 +31,229 @@
 //! ## License
 //!
 //! Licensed under both the MIT License and the Apache 2.0 License.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![forbid(unsafe_code)]
+
+extern crate alloc;
+
+#[cfg(feature = "api")]
+mod api;
+pub mod batch;
+#[cfg(feature = "bitbucket")]
+pub mod bitbucket;
+pub mod builder;
+pub mod discussion;
+#[cfg(feature = "environment")]
+mod environment;
 pub mod error;
+pub mod gitlab;
+mod markdown;
+pub mod owned;
+pub mod pull_request;
+mod remote;
+pub mod repo_ref;
+pub mod report_body;
+pub mod task_list;
+pub mod template;
+pub mod triage;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::iter;
+#[cfg(feature = "std")]
+use std::panic::PanicHookInfo;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 use url::Url;
 
+pub use self::batch::BatchBuilder;
+pub use self::builder::IssueBuilder;
+pub use self::discussion::Discussion;
+pub use self::gitlab::GitLabIssue;
+pub use self::owned::IssueOwned;
+pub use self::pull_request::PullRequest;
+pub use self::repo_ref::RepoRef;
+pub use self::report_body::ReportBody;
+pub use self::task_list::TaskList;
+#[cfg(feature = "std")]
+use self::template::expand_vars;
+pub use self::template::{BodyTemplate, Template};
+pub use self::triage::{Triage, TriageLabels};
+
 use self::error::{Error, Result};
 
+/// Creates an `IssueOwned` for the GitHub repository of the crate being
+/// compiled, as set in the `repository` field of its `Cargo.toml`.
+///
+/// The repository URL is read from `CARGO_PKG_REPOSITORY` at compile time.
+/// Returns `Error::RemoteParseFailed` if it's not set or is not a
+/// `github.com` repository URL.
+///
+/// # Example
+///
+/// ```
+/// let mut issue = github_issue_url::issue_for_crate!().unwrap();
+///
+/// issue.title("Null: The Billion Dollar Mistake");
+///
+/// assert_eq!(
+///     issue.url().unwrap(),
+///     "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake"
+/// );
+/// ```
+#[macro_export]
+macro_rules! issue_for_crate {
+    () => {
+        $crate::Issue::__from_crate_repository(env!("CARGO_PKG_REPOSITORY"))
+    };
+}
+
+/// Base URL used when no custom base URL is provided through
+/// `Issue::with_base_url`
+pub(crate) const GITHUB_BASE_URL: &str = "https://github.com";
+
+/// Chars percent-encoded in URL path segments, same as the path segment set of
+/// the WHATWG URL standard with `\` added as it's a path separator in `http`
+/// and `https` URLs
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}')
+    .add(b'/')
+    .add(b'%')
+    .add(b'\\');
+
+/// Order of well known params when sorted with `Issue::sort_params`, other
+/// params follow sorted alphabetically
+const CANONICAL_PARAMS_ORDER: [&str; 8] = [
+    "title",
+    "body",
+    "labels",
+    "assignee",
+    "assignees",
+    "milestone",
+    "projects",
+    "template",
+];
+
+/// Maximum issue title length in chars accepted by GitHub, longer titles are
+/// silently truncated. Refer to `Issue::title_truncated`.
+pub const TITLE_MAX_LENGTH: usize = 256;
+
+/// Params GitHub expects a single value for
+pub(crate) const SINGLE_VALUED_PARAMS: [&str; 5] =
+    ["title", "body", "assignee", "milestone", "template"];
+
+/// Params GitHub ignores unless the issue author has write access to the
+/// repository
+const WRITE_ACCESS_PARAMS: [&str; 5] = ["assignee", "assignees", "labels", "milestone", "projects"];
+
+/// Params GitHub ignores when the template is a YAML issue form, which sets
+/// them in the form file instead, or has no such field in the case of `body`
+#[cfg(feature = "log")]
+const FORM_IGNORED_PARAMS: [&str; 3] = ["body", "milestone", "projects"];
+
+/// Maximum length in chars of titles taken from the body by
+/// `Issue::derive_title_from_body`
+const DERIVED_TITLE_MAX_LENGTH: usize = 80;
+
+/// Appended to the title when shortened by `Issue::title_truncated`
+const TRUNCATED_TITLE_SUFFIX: char = '…';
+
+/// Appended to the body when shortened by `Issue::truncate_body_to_fit`
+const TRUNCATED_BODY_SUFFIX: &str = "…[truncated]";
+
+/// How spaces are encoded in the URL query, set with `Issue::space_encoding`.
+///
+/// GitHub parses the query of the "New Issue", "New Discussion" and compare
+/// pages as form data, so both encodings prefill fields with spaces. Tools
+/// which decode the query as a regular URI component instead, such as some
+/// link previews or chat clients, keep `+` as a literal plus sign, use
+/// `SpaceEncoding::Percent` when the URL goes through them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpaceEncoding {
+    /// Spaces are encoded as `+`, same as HTML forms do
+    #[default]
+    Plus,
+    /// Spaces are encoded as `%20`
+    Percent,
+}
+
+/// How the URL query is percent-encoded, set with `Issue::encoding_profile`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EncodingProfile {
+    /// Form-urlencoded, as done by the `url` crate, which leaves only ASCII
+    /// alphanumerics and `*-._` unescaped
+    #[default]
+    Strict,
+    /// Close to JavaScript `encodeURIComponent`, which links built in the
+    /// browser use: `!()~` are also left unescaped and spaces are encoded as
+    /// `%20`, regardless of `Issue::space_encoding`. Single quotes (`'`) are
+    /// still escaped, as required for the query of `http` and `https` URLs
+    /// by the WHATWG URL standard.
+    GitHubCompatible,
+}
+
+/// Keyword used to reference another issue with `Issue::reference_issue`.
+///
+/// `Closes`, `Fixes` and `Resolves` are GitHub closing keywords, which close
+/// the referenced issue once a pull request including them is merged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Reference {
+    Closes,
+    Fixes,
+    Resolves,
+    RelatedTo,
+}
+
+impl Reference {
+    const ALL: [Reference; 4] = [
+        Reference::Closes,
+        Reference::Fixes,
+        Reference::Resolves,
+        Reference::RelatedTo,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Reference::Closes => "Closes",
+            Reference::Fixes => "Fixes",
+            Reference::Resolves => "Resolves",
+            Reference::RelatedTo => "Related to",
+        }
+    }
+}
+
 /// GitHub issue struct with support for every field available.
 ///
 /// This struct is holds repository, username or organization name and
 /// fields to prefill when opening the issue url.
 ///
+/// Issues are equal when their params were set in the same order, use
+/// `Issue::semantically_eq` to ignore the order of params.
+///
+/// Setters for text values, such as `Issue::title` or `Issue::body`, accept
+/// any `Into<Cow<'a, str>>`: `&str`, `&String`, `String` and `Cow<str>`.
+/// Borrowed values are kept as is, owned values are moved into the `Issue`.
+/// `IssueOwned` setters accept any `Into<String>` instead.
+///
 /// # Example
 ///
 /// ```
@@ -59,136 +271,4442 @@ use self::error::{Error, Result};
 /// have.assignee("EstebanBorai");
 /// have.milestone("1");
 /// have.projects("1");
-
+///
 /// let have = have.url().unwrap();
 ///
 /// assert_eq!(have, GITHUB_ISSUE_LINK.to_string());
 /// ```
 ///
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Issue<'a> {
-    repository_name: &'a str,
-    repository_owner: &'a str,
-    params: Vec<(&'a str, &'a str)>,
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    repo: RepoRef<'a>,
+    params: Vec<(&'a str, Cow<'a, str>)>,
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    options: Options,
 }
 
 /// GitHub Issue including the repository name and the repository owner username.
 ///
-/// Issue fields are kept in a `Vec<(&'a str, &'a str)>` for easy parsing when
+/// Issue fields are kept in a `Vec<(&'a str, Cow<'a, str>)>` for easy parsing when
 /// parsing the URL with query params.
 ///
 /// Every optional param is specified using the setter methods.
 impl<'a> Issue<'a> {
+    /// Practical length limit in bytes of a prefilled issue URL.
+    ///
+    /// This is an empirical GitHub limit rather than part of the URL spec,
+    /// GitHub fails to open longer URLs. `Issue::url_checked` uses it when no
+    /// custom limit is provided through `Issue::url_length_limit`.
+    pub const MAX_URL_LENGTH: usize = 8192;
+
+    /// Creates an `Issue` for the provided repository.
+    ///
+    /// Returns `Error::EmptyRepositoryName` or `Error::EmptyRepositoryOwner`
+    /// if any of them is empty, and `Error::InvalidRepositoryName` or
+    /// `Error::InvalidRepositoryOwner` if they don't follow GitHub naming
+    /// rules:
+    ///
+    /// - Owner: up to 39 alphanumeric characters or single hyphens (`-`),
+    ///   not beginning nor ending with a hyphen
+    /// - Repository: up to 100 alphanumeric characters, hyphens (`-`),
+    ///   underscores (`_`) or periods (`.`)
     pub fn new(repository_name: &'a str, repository_owner: &'a str) -> Result<Self> {
-        if repository_name.is_empty() {
-            return Err(Error::EmptyRepositoryName);
+        Ok(Issue::from_repo(RepoRef::new(
+            repository_owner,
+            repository_name,
+        )?))
+    }
+
+    /// Creates an `Issue` without validating the repository name and owner,
+    /// for those cases where GitHub naming rules checked by `Issue::new` are
+    /// too strict.
+    ///
+    /// Repository name and owner are percent-encoded as URL path segments, so
+    /// characters such as spaces or slashes (`/`) never produce an invalid
+    /// URL.
+    pub fn new_unchecked(repository_name: &'a str, repository_owner: &'a str) -> Self {
+        Issue::from_repo(RepoRef::new_unchecked(repository_owner, repository_name))
+    }
+
+    /// Creates an `Issue` for `repo`. Refer to `RepoRef::issue`.
+    pub(crate) fn from_repo(repo: RepoRef<'a>) -> Self {
+        Issue {
+            repo,
+            params: Vec::new(),
+            options: Options::default(),
+        }
+    }
+
+    /// Creates an `Issue` from an `<owner>/<repository>` slug, such as
+    /// `EstebanBorai/github-issue-url`.
+    ///
+    /// Returns `Error::InvalidSlug` if the slug doesn't have exactly two
+    /// components separated by a slash (`/`), and the same errors as
+    /// `Issue::new` if any of them is empty.
+    pub fn from_slug(slug: &'a str) -> Result<Self> {
+        let (repository_owner, repository_name) = slug
+            .split_once('/')
+            .filter(|(_, repository_name)| !repository_name.contains('/'))
+            .ok_or_else(|| Error::InvalidSlug(slug.to_string()))?;
+
+        Issue::new(repository_name, repository_owner)
+    }
+
+    /// Parses a "New Issue" URL such as
+    /// `https://github.com/<owner>/<repository>/issues/new?title=...` back into
+    /// an `IssueOwned`, decoding every query param.
+    ///
+    /// Params are kept in the order they appear in the URL, with one param per
+    /// occurrence of repeated keys such as `assignees`, so building the URL
+    /// again with `IssueOwned::url` returns the same URL.
+    ///
+    /// If the URL host is not `github.com` the origin of the URL is kept as
+    /// the base URL.
+    ///
+    /// Returns `Error::UrlParseError` if the URL is malformed and
+    /// `Error::NotAnIssueUrl` if the URL path doesn't match
+    /// `<owner>/<repository>/issues/new`.
+    pub fn from_url(url: &str) -> Result<IssueOwned> {
+        Issue::from_parsed_url(&Url::parse(url)?)
+    }
+
+    /// Same as `Issue::from_url`, for a URL already parsed as a `url::Url`.
+    ///
+    /// Returns `Error::NotAnIssueUrl` if the URL path doesn't match
+    /// `<owner>/<repository>/issues/new`, optionally followed by a trailing
+    /// slash. Paths with empty segments, such as
+    /// `//<owner>/<repository>/issues/new`, point to a different page and
+    /// are rejected too.
+    pub fn from_parsed_url(url: &Url) -> Result<IssueOwned> {
+        let mut segments: Vec<&str> = url
+            .path_segments()
+            .map(Iterator::collect)
+            .unwrap_or_default();
+
+        if segments.len() > 1 && segments.last() == Some(&"") {
+            segments.pop();
         }
 
-        if repository_owner.is_empty() {
-            return Err(Error::EmptyRepositoryOwner);
+        let (repository_owner, repository_name) = match segments.as_slice() {
+            [owner, repository, "issues", "new"] if !owner.is_empty() && !repository.is_empty() => {
+                (*owner, *repository)
+            }
+            _ => return Err(Error::NotAnIssueUrl),
+        };
+
+        let mut issue = IssueOwned::new(repository_name, repository_owner)?;
+        let origin = url.origin().ascii_serialization();
+
+        if origin != GITHUB_BASE_URL {
+            issue.with_base_url(&origin)?;
         }
 
-        Ok(Issue {
-            repository_name,
-            repository_owner,
-            params: Vec::new(),
-        })
+        for (key, value) in url.query_pairs() {
+            issue.push_param(key, value);
+        }
+
+        Ok(issue)
     }
 
-    /// The username of the issue's assignee.
+    /// Creates an `IssueOwned` from the information provided to a panic hook,
+    /// with the panic message as title and the panic location
+    /// (`file:line:column`) as a markdown code block in the body.
     ///
-    /// The issue author requires write access to the repository in order to
-    /// use this feature
-    pub fn assignee(&mut self, assignee: &'a str) {
-        self.params.push(("assignee", assignee));
+    /// # Example
+    ///
+    /// ```no_run
+    /// use github_issue_url::Issue;
+    ///
+    /// std::panic::set_hook(Box::new(|info| {
+    ///     if let Ok(issue) = Issue::from_panic_info("github-issue-url", "EstebanBorai", info) {
+    ///         eprintln!("Please report this issue: {}", issue);
+    ///     }
+    /// }));
+    /// ```
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn from_panic_info(
+        repository_name: &str,
+        repository_owner: &str,
+        info: &PanicHookInfo<'_>,
+    ) -> Result<IssueOwned> {
+        let mut issue = IssueOwned::new(repository_name, repository_owner)?;
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("Box<dyn Any>");
+
+        issue.title(message);
+
+        if let Some(location) = info.location() {
+            let location = format!(
+                "{}:{}:{}",
+                location.file(),
+                location.line(),
+                location.column()
+            );
+
+            issue.body(markdown::code_block("", &location));
+        }
+
+        Ok(issue)
     }
 
-    /// Prefilled issue body content
-    pub fn body(&mut self, body: &'a str) {
-        self.params.push(("body", body));
+    /// Creates an `IssueOwned` for the GitHub repository referenced by the
+    /// `origin` remote of the git repository at `path`.
+    ///
+    /// Both SSH (`git@github.com:owner/repo.git`) and HTTPS
+    /// (`https://github.com/owner/repo.git`) remote URLs are supported, if the
+    /// remote host is not `github.com` it's used as the base URL.
+    ///
+    /// Returns `Error::GitError` if the git repository or its `origin` remote
+    /// can't be read and `Error::RemoteParseFailed` if the remote URL is not
+    /// recognized.
+    ///
+    /// Requires the `git` feature.
+    #[cfg(feature = "git")]
+    pub fn from_local_git(path: &Path) -> Result<IssueOwned> {
+        let repository =
+            git2::Repository::discover(path).map_err(|e| Error::GitError(e.to_string()))?;
+        let origin = repository
+            .find_remote("origin")
+            .map_err(|e| Error::GitError(e.to_string()))?;
+
+        Issue::from_repository_url(origin.url().unwrap_or_default())
     }
 
-    /// Issue labels separated by comma (`,`).
-    /// Example: `bug,production,high-severity`
+    /// Creates an `IssueOwned` for the GitHub repository at `url`, such as the
+    /// `repository` field of a `Cargo.toml` or a git remote URL.
     ///
-    /// The issue author requires write access to the repository in order to
-    /// use this feature
-    pub fn labels(&mut self, labels: &'a str) {
-        self.params.push(("labels", labels));
+    /// Both SSH (`git@github.com:owner/repo.git`) and HTTPS
+    /// (`https://github.com/owner/repo.git`) URLs are supported, if the host
+    /// is not `github.com` it's used as the base URL.
+    ///
+    /// Returns `Error::RemoteParseFailed` if the URL is not recognized.
+    pub fn from_repository_url(url: &str) -> Result<IssueOwned> {
+        let remote =
+            remote::parse_remote(url).ok_or_else(|| Error::RemoteParseFailed(url.to_string()))?;
+        let mut issue = IssueOwned::new(remote.repository, remote.owner)?;
+
+        if remote.host != "github.com" {
+            issue.with_base_url(&format!("https://{}", remote.host))?;
+        }
+
+        Ok(issue)
     }
 
-    /// The ID (number) of the milestone linked to this issue.
+    /// Creates an `IssueOwned` for the GitHub repository set as
+    /// `package.repository` in the `Cargo.toml` at `path`.
     ///
-    /// The milestone ID can be found in the Issues/Milestone section.
+    /// Unlike `issue_for_crate!`, the manifest is read at runtime, which is
+    /// useful when its location is only known once the program is running.
     ///
-    /// https://github.com/<owner>/<repository>/milestone/<milestone id>
+    /// Returns `Error::Io` if the file can't be read, `Error::ManifestParse`
+    /// if it's not valid TOML or has no `package.repository` string, and
+    /// `Error::RemoteParseFailed` if the repository URL is not recognized.
+    ///
+    /// Requires the `cargo` feature.
+    #[cfg(feature = "cargo")]
+    pub fn from_cargo_manifest(path: &Path) -> Result<IssueOwned> {
+        let manifest = std::fs::read_to_string(path).map_err(|e| Error::Io(e.to_string()))?;
+        let manifest = manifest
+            .parse::<toml::Table>()
+            .map_err(|e| Error::ManifestParse(e.to_string()))?;
+        let repository = manifest
+            .get("package")
+            .and_then(|package| package.get("repository"))
+            .and_then(toml::Value::as_str)
+            .ok_or_else(|| Error::ManifestParse(String::from("`package.repository` is not set")))?;
+
+        Issue::from_repository_url(repository)
+    }
+
+    /// Used by `issue_for_crate!`, which only accepts `github.com` URLs as
+    /// `CARGO_PKG_REPOSITORY` may point to any forge
+    #[doc(hidden)]
+    pub fn __from_crate_repository(repository: &str) -> Result<IssueOwned> {
+        match remote::parse_remote(repository) {
+            Some(remote) if remote.host == "github.com" => {
+                IssueOwned::new(remote.repository, remote.owner)
+            }
+            _ => Err(Error::RemoteParseFailed(repository.to_string())),
+        }
+    }
+
+    /// Wraps `content` in a markdown fenced code block tagged with `lang`,
+    /// to be used as part of the issue body.
+    ///
+    /// If `content` includes fences itself, a longer fence is used (four or
+    /// more backticks) so the code block isn't broken.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let body = Issue::code_block("rust", "fn main() {}");
+    ///
+    /// assert_eq!(body, "```rust\nfn main() {}\n```");
+    /// ```
+    pub fn code_block(lang: &str, content: &str) -> String {
+        markdown::code_block(lang, content)
+    }
+
+    /// Wraps `content` in a collapsible `<details>` block labeled with
+    /// `summary`, useful to fold long logs in the issue body.
+    ///
+    /// `summary` is HTML escaped, as it's rendered as HTML, whereas `content`
+    /// is kept as is so its markdown renders. Content is surrounded by blank
+    /// lines, which GitHub requires to render markdown inside the block.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let logs = Issue::code_block("", "thread 'main' panicked");
+    /// let body = Issue::details_block("Logs", &logs);
+    ///
+    /// assert_eq!(
+    ///     body,
+    ///     "<details>\n<summary>Logs</summary>\n\n```\nthread 'main' panicked\n```\n\n</details>"
+    /// );
+    /// ```
+    pub fn details_block(summary: &str, content: &str) -> String {
+        markdown::details_block(summary, content)
+    }
+
+    /// Renders a GitHub-flavored markdown table, such as a list of
+    /// dependencies and their versions, to be used as part of the issue body.
+    ///
+    /// Pipes (`|`) in cells are escaped as `\|` and newlines are replaced by
+    /// `<br>`. Returns `Error::TableShapeMismatch` with the index of the
+    /// first row without one cell per header.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let rows = vec![
+    ///     vec![String::from("url"), String::from("2.5")],
+    ///     vec![String::from("thiserror"), String::from("2")],
+    /// ];
+    /// let body = Issue::markdown_table(&["Crate", "Version"], &rows).unwrap();
+    ///
+    /// assert_eq!(
+    ///     body,
+    ///     "| Crate | Version |\n| --- | --- |\n| url | 2.5 |\n| thiserror | 2 |"
+    /// );
+    /// ```
+    pub fn markdown_table(headers: &[&str], rows: &[Vec<String>]) -> Result<String> {
+        markdown::table(headers, rows)
+    }
+
+    /// Markdown section describing the host system: OS name, OS version and
+    /// architecture, ready to be appended to the body with
+    /// `Issue::append_body`.
+    ///
+    /// Requires the `environment` feature.
+    #[cfg(feature = "environment")]
+    pub fn system_info_body() -> String {
+        environment::system_info(None)
+    }
+
+    /// Same as `Issue::system_info_body` but also includes the version of
+    /// your application, usually `env!("CARGO_PKG_VERSION")`.
+    ///
+    /// The version has to be provided by the caller, as `env!` expanded in
+    /// this crate would report the version of `github-issue-url` instead.
+    ///
+    /// Requires the `environment` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let system_info = Issue::system_info_body_with_version(env!("CARGO_PKG_VERSION"));
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.body("Panics on startup");
+    /// issue.append_body(&system_info);
+    /// ```
+    #[cfg(feature = "environment")]
+    pub fn system_info_body_with_version(version: &str) -> String {
+        environment::system_info(Some(version))
+    }
+
+    /// Builds a markdown image (`![alt](url)`) to include an already uploaded
+    /// image in the body.
+    ///
+    /// Backslashes, brackets and parentheses in `alt`, and parentheses in
+    /// `url` are escaped with a backslash.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut body = String::from("Button is misaligned\n\n");
+    ///
+    /// body.push_str(&Issue::image_markdown("Screenshot", "https://example.com/screenshot.png"));
+    /// body.push('\n');
+    /// body.push_str(&Issue::link_markdown("Logs [debug]", "https://example.com/logs.txt"));
+    ///
+    /// assert_eq!(
+    ///     body,
+    ///     "Button is misaligned\n\n![Screenshot](https://example.com/screenshot.png)\n[Logs \\[debug\\]](https://example.com/logs.txt)"
+    /// );
+    /// ```
+    pub fn image_markdown(alt: &str, url: &str) -> String {
+        markdown::image(alt, url)
+    }
+
+    /// Builds a markdown link (`[text](url)`), such as a link to an already
+    /// uploaded log file. Escapes `text` and `url` same as
+    /// `Issue::image_markdown`.
+    pub fn link_markdown(text: &str, url: &str) -> String {
+        markdown::link(text, url)
+    }
+
+    /// Creates an `IssueBuilder` for the provided repository, which allows
+    /// setting every field by chaining method calls.
+    ///
+    /// Repository name and owner are validated when `IssueBuilder::build` is
+    /// called.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let have = Issue::builder("github-issue-url", "EstebanBorai")
+    ///     .title("Null: The Billion Dollar Mistake")
+    ///     .body("Null is a flag.")
+    ///     .append_body("It represents different situations.")
+    ///     .milestone_id(1)
+    ///     .build()
+    ///     .unwrap()
+    ///     .url()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(have, "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake&body=Null+is+a+flag.%0AIt+represents+different+situations.&milestone=1");
+    /// ```
+    pub fn builder(repository_name: &'a str, repository_owner: &'a str) -> IssueBuilder<'a> {
+        IssueBuilder::new(repository_name, repository_owner)
+    }
+
+    /// The username of the issue's assignee, replacing any assignee previously
+    /// set. Use `Issue::assignees` to assign more than one user.
     ///
     /// The issue author requires write access to the repository in order to
     /// use this feature
-    pub fn milestone(&mut self, milestone: &'a str) {
-        self.params.push(("milestone", milestone));
+    pub fn assignee(&mut self, assignee: impl Into<Cow<'a, str>>) {
+        self.set_param("assignee", assignee);
     }
 
-    /// The IDs (number) of the projects to link this issue to separated by
-    /// comma (`,`).
+    /// Assigns the issue to whoever opens it, with the special `@me`
+    /// assignee, replacing any assignee previously set.
     ///
-    /// Projects IDs are found in the repository session.
+    /// The `@` is percent-encoded as `%40`, which GitHub decodes back to
+    /// `@me` same as any other query param value.
     ///
-    /// https://github.com/<owner>/<repository>/projects/<project id>
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.assign_to_self();
+    ///
+    /// assert_eq!(
+    ///     issue.url().unwrap(),
+    ///     "https://github.com/EstebanBorai/github-issue-url/issues/new?assignee=%40me"
+    /// );
+    /// ```
+    pub fn assign_to_self(&mut self) {
+        self.set_param("assignee", "@me");
+    }
+
+    /// The usernames of the issue's assignees.
+    ///
+    /// Unlike `Issue::assignee` which sets the `assignee` query param to a
+    /// single username, a repeated `assignees` query param is pushed for each
+    /// username (e.g. `assignees=alice&assignees=bob`), GitHub assigns every
+    /// one of them.
     ///
     /// The issue author requires write access to the repository in order to
     /// use this feature
-    pub fn projects(&mut self, projects: &'a str) {
-        self.params.push(("projects", projects));
+    pub fn assignees(&mut self, assignees: &[&'a str]) {
+        for assignee in assignees {
+            self.push_param("assignees", *assignee);
+        }
     }
 
-    /// Prefilled issue title
-    pub fn title(&mut self, title: &'a str) {
-        self.params.push(("title", title));
+    /// Human-readable summary of the fields prefilled by the URL returned by
+    /// `Issue::url`, as `(name, value)` pairs to display in a UI.
+    ///
+    /// Names are capitalized for known params (`Title`, `Labels`, ...) and
+    /// kept as is for any other param. Repeated params and comma separated
+    /// `labels` and `projects` are merged into a single entry with values
+    /// joined by `, `. Unlike `Issue::params`, params left out of the URL
+    /// are not listed, while a title derived from the body or a footer are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.title("Null: The Billion Dollar Mistake");
+    /// issue.labels("bug,production");
+    /// issue.assignees(&["EstebanBorai", "octocat"]);
+    /// issue.labels("high-severity");
+    ///
+    /// assert_eq!(
+    ///     issue.prefilled_fields(),
+    ///     vec![
+    ///         (String::from("Title"), String::from("Null: The Billion Dollar Mistake")),
+    ///         (String::from("Labels"), String::from("bug, production, high-severity")),
+    ///         (String::from("Assignees"), String::from("EstebanBorai, octocat")),
+    ///     ]
+    /// );
+    /// ```
+    pub fn prefilled_fields(&self) -> Vec<(String, String)> {
+        let mut fields: Vec<(&str, Vec<&str>)> = Vec::new();
+        let params = self.query_params();
+
+        for (key, value) in params.iter() {
+            let values: Vec<&str> = if *key == "labels" || *key == "projects" {
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .collect()
+            } else {
+                iter::once(value.as_ref()).collect()
+            };
+
+            match fields.iter_mut().find(|(field, _)| field == key) {
+                Some((_, current)) => current.extend(values),
+                None => fields.push((key, values)),
+            }
+        }
+
+        fields
+            .into_iter()
+            .map(|(key, values)| (field_name(key), values.join(", ")))
+            .collect()
     }
 
-    /// The name of the issue template to use when opening the final link.
-    /// An issue template lives in .github/ISSUE_TEMPLATE/<issue template name>.md,
-    /// if the template you want to use when opening this link is ISSUE_TEMPLATE/bugs.md
-    /// the value for `Issue.template` must be `bugs.md`
-    pub fn template(&mut self, template: &'a str) {
-        self.params.push(("template", template));
+    /// Params currently set on this `Issue`, in the same order they were
+    /// set.
+    ///
+    /// Values are `Cow`s given that some setters such as `Issue::milestone_id`
+    /// store formatted values.
+    pub fn params(&self) -> &[(&'a str, Cow<'a, str>)] {
+        &self.params
     }
 
-    pub fn url(&'a self) -> Result<String> {
-        let repository_url = format!(
-            "https://github.com/{}/{}/issues/new",
-            self.repository_owner, self.repository_name
-        );
-        let url = Url::parse_with_params(repository_url.as_str(), self.params.iter())
-            .map_err(|e| Error::UrlParseError(e.to_string()))?;
+    /// Number of params currently set, counting each repeated param
+    pub fn param_count(&self) -> usize {
+        self.params.len()
+    }
 
-        Ok(url.to_string())
+    /// Whether any param is set, useful to tell a prefilled URL apart from a
+    /// bare "New Issue" URL
+    pub fn has_params(&self) -> bool {
+        !self.params.is_empty()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Compares two issues ignoring the order in which params were set.
+    ///
+    /// `PartialEq` takes the order of params into account, as it changes the
+    /// generated URL. This compares params as a multiset instead, so each
+    /// repeated param must appear the same number of times in both issues.
+    /// Repository and options must be equal too.
+    pub fn semantically_eq(&self, other: &Issue<'_>) -> bool {
+        self.repo == other.repo
+            && self.options == other.options
+            && sorted_params(&self.params) == sorted_params(&other.params)
+    }
 
-    const GITHUB_ISSUE_LINK: &str = "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake&body=Null+is+a+flag.+It+represents+different+situations+depending+on+the+context+in+which+it+is+used+and+invoked.+This+yields+the+most+serious+error+in+software+development%3A+Coupling+a+hidden+decision+in+the+contract+between+an+object+and+who+uses+it.&template=bug_report.md&labels=bug%2Cproduction%2Chigh-severity&assignee=EstebanBorai&milestone=1&projects=1";
-    const SAMPLE_ISSUE_BODY: &str = r#"Null is a flag. It represents different situations depending on the context in which it is used and invoked. This yields the most serious error in software development: Coupling a hidden decision in the contract between an object and who uses it."#;
+    /// Canonical string describing the repository, the params and the
+    /// options, to be used as a cache key for generated URLs.
+    ///
+    /// The key depends on the params and on the options which affect the URL
+    /// built by `Issue::url`, regardless of the order params were set in:
+    /// it's built same as the URL, but with the query params sorted by key
+    /// and value. Issues setting the same params in a different order share
+    /// a key even though their URLs differ. Don't rely on the key format,
+    /// only on its equality.
+    ///
+    /// `Issue` doesn't implement `Ord`, as ordering by this key would tell
+    /// apart issues `PartialEq` considers equal, or the other way around.
+    /// Use `sort_by_cached_key(Issue::cache_key)` to sort issues instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut a = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    /// let mut b = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// a.title("Null: The Billion Dollar Mistake");
+    /// a.labels("bug");
+    /// b.labels("bug");
+    /// b.title("Null: The Billion Dollar Mistake");
+    ///
+    /// assert_ne!(a.url().unwrap(), b.url().unwrap());
+    /// assert_eq!(a.cache_key(), b.cache_key());
+    /// ```
+    pub fn cache_key(&self) -> String {
+        let mut key = String::new();
 
-    #[test]
-    fn build_issue_url() {
-        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+        self.write_url_with_order(&mut key, true);
+        key
+    }
 
-        have.title("Null: The Billion Dollar Mistake");
-        have.body(SAMPLE_ISSUE_BODY);
-        have.template("bug_report.md");
-        have.labels("bug,production,high-severity");
-        have.assignee("EstebanBorai");
-        have.milestone("1");
-        have.projects("1");
+    /// Whether a param with the provided `key` is set
+    pub fn contains_param(&self, key: &str) -> bool {
+        self.params.iter().any(|(param, _)| *param == key)
+    }
 
-        let have = have.url().unwrap();
+    /// Value of the first param with the provided `key`, as set before URL
+    /// encoding
+    pub fn param_value(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| *param == key)
+            .map(|(_, value)| value.as_ref())
+    }
 
-        assert_eq!(have, GITHUB_ISSUE_LINK.to_string());
+    /// Removes every param so the `Issue` can be reused to build a different
+    /// URL for the same repository.
+    pub fn clear_params(&mut self) {
+        self.params.clear();
+    }
+
+    /// Points the `Issue` to a different repository and removes every param,
+    /// keeping the allocated capacity for params and every option set, such
+    /// as the base URL.
+    ///
+    /// Useful to build many URLs for different repositories without
+    /// allocating a new `Issue` each time. Returns the same errors as
+    /// `Issue::new`, leaving the `Issue` untouched.
+    pub fn reset_to(&mut self, repository_name: &'a str, repository_owner: &'a str) -> Result<()> {
+        self.repo.reset_to(repository_owner, repository_name)?;
+        self.params.clear();
+
+        Ok(())
+    }
+
+    /// Removes every param with the provided `key`
+    pub fn remove_param(&mut self, key: &str) {
+        self.params.retain(|(param, _)| *param != key);
+    }
+
+    /// Leaves every param with the provided `key` out of the URL, keeping
+    /// their values so they can be included again with `Issue::enable_param`.
+    ///
+    /// Params set later with the same `key` are left out as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.title("Null: The Billion Dollar Mistake");
+    /// issue.labels("bug");
+    /// issue.disable_param("labels");
+    ///
+    /// assert!(!issue.is_param_enabled("labels"));
+    /// assert_eq!(issue.param_value("labels"), Some("bug"));
+    /// assert_eq!(
+    ///     issue.url().unwrap(),
+    ///     "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake"
+    /// );
+    /// ```
+    pub fn disable_param(&mut self, key: &str) {
+        self.options.disable_param(key);
+    }
+
+    /// Includes params with the provided `key` in the URL again, after being
+    /// left out with `Issue::disable_param`
+    pub fn enable_param(&mut self, key: &str) {
+        self.options.enable_param(key);
+    }
+
+    /// Whether params with the provided `key` are included in the URL, `true`
+    /// unless disabled with `Issue::disable_param`
+    pub fn is_param_enabled(&self, key: &str) -> bool {
+        self.options.is_param_enabled(key)
+    }
+
+    /// Prefilled issue body content.
+    ///
+    /// Replaces any body previously set, use `Issue::append_body` to add
+    /// content to the current body instead. An empty body removes the body
+    /// param, so the URL doesn't include an empty `body=`.
+    pub fn body(&mut self, body: impl Into<Cow<'a, str>>) {
+        let body = body.into();
+
+        if body.is_empty() {
+            return self.remove_param("body");
+        }
+
+        self.set_param("body", body);
+    }
+
+    /// Reads the file at `path` and uses its content as the body, replacing
+    /// any body previously set.
+    ///
+    /// Newlines are normalized when building the URL same as any other body,
+    /// refer to `Issue::normalize_newlines`.
+    ///
+    /// Returns `Error::Io` if the file can't be read or is not valid UTF-8.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn body_from_file(&mut self, path: &Path) -> Result<()> {
+        let body = std::fs::read_to_string(path).map_err(|e| Error::Io(e.to_string()))?;
+
+        self.body(body);
+
+        Ok(())
+    }
+
+    /// Uses `template` as the body, replacing each `${NAME}` occurrence with
+    /// the value of the `NAME` environment variable and `$$` with a literal
+    /// `$`. Replaces any body previously set.
+    ///
+    /// Returns `Error::MissingEnvVar` with the variable name if any variable
+    /// is not set, in which case the body is left unchanged.
+    ///
+    /// Requires the `std` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.body_from_env_template("Cargo: ${CARGO} costs $$0").unwrap();
+    ///
+    /// assert!(issue.param_value("body").unwrap().ends_with("costs $0"));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn body_from_env_template(&mut self, template: &str) -> Result<()> {
+        let body = expand_vars(template, |name| std::env::var(name).ok())?;
+
+        self.body(body);
+
+        Ok(())
+    }
+
+    /// Uses `error` and its chain of sources as the body, replacing any body
+    /// previously set.
+    ///
+    /// The error message and the message of each source, listed under
+    /// `Caused by:`, are wrapped in a markdown code block so they are
+    /// rendered as is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let error = "one".parse::<u8>().unwrap_err();
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.body_from_error(&error);
+    ///
+    /// assert_eq!(
+    ///     issue.url().unwrap(),
+    ///     "https://github.com/EstebanBorai/github-issue-url/issues/new?body=%60%60%60%0Ainvalid+digit+found+in+string%0A%60%60%60"
+    /// );
+    /// ```
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn body_from_error(&mut self, error: &dyn std::error::Error) {
+        self.set_param("body", markdown::error_chain(error));
+    }
+
+    /// Appends content to the body, separated from the current body by a
+    /// newline (`\n`). If no body is set, it behaves as `Issue::body`.
+    pub fn append_body(&mut self, body: impl Into<Cow<'a, str>>) {
+        let body = body.into();
+        let body = match self.param_value("body") {
+            Some(current) => Cow::Owned(format!("{}\n{}", current, body)),
+            None => return self.body(body),
+        };
+
+        self.set_param("body", body);
+    }
+
+    /// Renders `facts`, such as the application version or the OS, as a
+    /// markdown list with one `- name: value` item each and appends it to the
+    /// body, separated from the current body by a blank line. If no body is
+    /// set, the list is used as the body.
+    ///
+    /// Nothing is appended if `facts` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.body("Panics on startup");
+    /// issue.body_from_facts(&[("App version", "1.2.3"), ("OS", "macOS 14")]);
+    ///
+    /// assert_eq!(
+    ///     issue.param_value("body"),
+    ///     Some("Panics on startup\n\n- App version: 1.2.3\n- OS: macOS 14")
+    /// );
+    /// ```
+    pub fn body_from_facts(&mut self, facts: &[(&str, &str)]) {
+        if facts.is_empty() {
+            return;
+        }
+
+        let facts = markdown::facts_list(facts);
+        let body = match self.param_value("body") {
+            Some(current) => format!("{}\n\n{}", current, facts),
+            None => facts,
+        };
+
+        self.set_param("body", body);
+    }
+
+    /// Footer appended to the body when building the URL, separated from the
+    /// body by a markdown horizontal rule (`---`). If no body is set, the
+    /// footer is used as the body.
+    ///
+    /// The body returned by `Issue::param_value` is kept as is, so the footer
+    /// is never added twice. When `Issue::truncate_body_to_fit` is set, only
+    /// the body is shortened and the footer is always kept whole.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.body("Panics on startup");
+    /// issue.with_footer("_Filed by MyApp v1.2.3_");
+    ///
+    /// assert_eq!(
+    ///     issue.url().unwrap(),
+    ///     "https://github.com/EstebanBorai/github-issue-url/issues/new?body=Panics+on+startup%0A%0A---%0A%0A_Filed+by+MyApp+v1.2.3_"
+    /// );
+    /// ```
+    pub fn with_footer(&mut self, footer: impl Into<Cow<'a, str>>) {
+        self.options.footer = Some(footer.into().into_owned());
+    }
+
+    /// References issue `number` at the start of the body with a line such
+    /// as `Closes #123` or `Related to #123`.
+    ///
+    /// References are kept at the start of the body in the order they are
+    /// added, separated from the rest of the body by a blank line. Adding the
+    /// same reference twice has no effect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::{Issue, Reference};
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.body("Labels are not encoded.");
+    /// issue.reference_issue(Reference::Fixes, 12);
+    /// issue.reference_issue(Reference::RelatedTo, 7);
+    ///
+    /// assert_eq!(
+    ///     issue.param_value("body"),
+    ///     Some("Fixes #12\nRelated to #7\n\nLabels are not encoded.")
+    /// );
+    /// ```
+    pub fn reference_issue(&mut self, keyword: Reference, number: u64) {
+        let current = self.param_value("body").unwrap_or_default();
+
+        if let Some(body) = referenced_body(current, keyword, number) {
+            self.set_param("body", body);
+        }
+    }
+
+    /// Arbitrary query param, useful to prefill fields of
+    /// [issue forms](https://docs.github.com/en/communities/using-templates-to-encourage-useful-issues-and-pull-requests/syntax-for-issue-forms)
+    /// by their `id`, for instance `browser=Firefox` or `version=1.2.3`.
+    ///
+    /// Returns `Error::EmptyParamKey` if `key` is empty.
+    pub fn custom_param(&mut self, key: &'a str, value: impl Into<Cow<'a, str>>) -> Result<()> {
+        self.push_checked_param(key, value)
+    }
+
+    /// Pushes a new `key` param with `value`, keeping any param previously set
+    /// with the same `key`, unlike setters for single-valued params such as
+    /// `Issue::title` which replace the current value.
+    ///
+    /// GitHub adds up repeated `assignees`, `labels` and `projects` params,
+    /// so each of them assigns, labels or links the issue. For `title`,
+    /// `body`, `assignee`, `milestone` and `template` a single value is used,
+    /// so repeating them is reported by `Issue::validate`.
+    ///
+    /// Returns `Error::EmptyParamKey` if `key` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.append_param("labels", "bug").unwrap();
+    /// issue.append_param("labels", "production").unwrap();
+    ///
+    /// assert_eq!(
+    ///     issue.url().unwrap(),
+    ///     "https://github.com/EstebanBorai/github-issue-url/issues/new?labels=bug&labels=production"
+    /// );
+    /// ```
+    pub fn append_param(&mut self, key: &'a str, value: impl Into<Cow<'a, str>>) -> Result<()> {
+        self.push_checked_param(key, value)
+    }
+
+    /// Prefills the fields of a YAML issue form, using `template` as the form
+    /// file name (e.g. `bug_report.yml`) and each field `id` as param key.
+    ///
+    /// Prefilling fields only works when `template` is a YAML issue form
+    /// from `.github/ISSUE_TEMPLATE`, markdown templates ignore these params.
+    /// Issue forms have no free-form body either, so `body` is ignored by
+    /// GitHub when a form is used.
+    ///
+    /// Returns `Error::EmptyParamKey` if any field `id` is empty, in which
+    /// case the `Issue` is left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue
+    ///     .form_fields("bug_report.yml", &[("version", "1.2.3"), ("browser", "Firefox")])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     issue.url().unwrap(),
+    ///     "https://github.com/EstebanBorai/github-issue-url/issues/new?template=bug_report.yml&version=1.2.3&browser=Firefox"
+    /// );
+    /// ```
+    pub fn form_fields(&mut self, template: &'a str, fields: &[(&'a str, &'a str)]) -> Result<()> {
+        if fields.iter().any(|(id, _)| id.is_empty()) {
+            return Err(Error::EmptyParamKey);
+        }
+
+        self.template(template);
+
+        for (id, value) in fields {
+            self.push_param(id, *value);
+        }
+
+        Ok(())
+    }
+
+    /// Issue labels separated by comma (`,`).
+    /// Example: `bug,production,high-severity`
+    ///
+    /// The issue author requires write access to the repository in order to
+    /// use this feature
+    pub fn labels(&mut self, labels: impl Into<Cow<'a, str>>) {
+        self.push_param("labels", labels);
+    }
+
+    /// The ID (number) of the milestone linked to this issue, replacing any
+    /// milestone previously set.
+    ///
+    /// The milestone ID can be found in the Issues/Milestone section.
+    ///
+    /// https://github.com/<owner>/<repository>/milestone/<milestone id>
+    ///
+    /// GitHub ignores the param if the value is not a number, which produces
+    /// a link without the milestone set. Prefer `Issue::milestone_id` which
+    /// only accepts numbers.
+    ///
+    /// The issue author requires write access to the repository in order to
+    /// use this feature
+    pub fn milestone(&mut self, milestone: impl Into<Cow<'a, str>>) {
+        self.set_param("milestone", milestone);
+    }
+
+    /// The ID (number) of the milestone linked to this issue, replacing any
+    /// milestone previously set.
+    ///
+    /// Refer to `Issue::milestone` for details on finding the milestone ID.
+    ///
+    /// The issue author requires write access to the repository in order to
+    /// use this feature
+    pub fn milestone_id(&mut self, milestone: u64) {
+        self.set_param("milestone", milestone.to_string());
+    }
+
+    /// The IDs (number) of the projects to link this issue to separated by
+    /// comma (`,`).
+    ///
+    /// Projects IDs are found in the repository session.
+    ///
+    /// https://github.com/<owner>/<repository>/projects/<project id>
+    ///
+    /// These are projects (classic), use `Issue::project_v2` to add the issue
+    /// to a project of the current Projects experience.
+    ///
+    /// The issue author requires write access to the repository in order to
+    /// use this feature
+    pub fn projects(&mut self, projects: impl Into<Cow<'a, str>>) {
+        self.push_param("projects", projects);
+    }
+
+    /// The IDs (number) of the projects to link this issue to, joined with
+    /// comma (`,`) into a single `projects` param.
+    ///
+    /// Refer to `Issue::projects` for details on project IDs.
+    pub fn projects_slice(&mut self, projects: &[u64]) {
+        self.push_param("projects", join_ids(projects));
+    }
+
+    /// Adds the issue to project `number` of user or organization `owner`,
+    /// as seen in the project URL.
+    ///
+    /// https://github.com/orgs/<owner>/projects/<number>
+    ///
+    /// This targets the current Projects experience, which GitHub reads as
+    /// `projects=<owner>/<number>`. Numeric project IDs set with
+    /// `Issue::projects` only work for projects (classic). The issue author
+    /// requires write access to the repository in order to use this feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.project_v2("octo-org", 1);
+    ///
+    /// assert_eq!(
+    ///     issue.url().unwrap(),
+    ///     "https://github.com/EstebanBorai/github-issue-url/issues/new?projects=octo-org%2F1"
+    /// );
+    /// ```
+    pub fn project_v2(&mut self, owner: &str, number: u64) {
+        self.push_param("projects", format!("{}/{}", owner, number));
+    }
+
+    /// Issue labels provided as a slice, joined with comma (`,`) into the
+    /// `labels` query param.
+    ///
+    /// Labels are percent-encoded along with the rest of the query, for
+    /// instance `["bug", "high severity"]` is encoded as
+    /// `labels=bug%2Chigh+severity`, and `#` or `&` as in `C#` or `Q&A` are
+    /// encoded as `%23` and `%26`. Given that comma (`,`) is the labels
+    /// separator, a label including a comma can't be disambiguated and
+    /// `Error::InvalidLabel` is returned.
+    ///
+    /// The issue author requires write access to the repository in order to
+    /// use this feature
+    pub fn labels_slice(&mut self, labels: &[&'a str]) -> Result<()> {
+        self.push_param("labels", join_labels(labels)?);
+
+        Ok(())
+    }
+
+    /// Looks up the number of the milestone titled `name` through the GitHub
+    /// REST API, to be used with `Issue::milestone_id`.
+    ///
+    /// Both open and closed milestones are searched, going through every page
+    /// of `GET /repos/{owner}/{repo}/milestones`. A `token` is only required
+    /// for private repositories.
+    ///
+    /// Returns `Error::MilestoneNotFound` if no milestone has that title and
+    /// `Error::ApiError` if the request fails.
+    ///
+    /// Requires the `api` feature.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use github_issue_url::Issue;
+    ///
+    /// async fn report() -> github_issue_url::error::Result<String> {
+    ///     let milestone =
+    ///         Issue::resolve_milestone("github-issue-url", "EstebanBorai", "v0.2.0", None).await?;
+    ///     let mut issue = Issue::new("github-issue-url", "EstebanBorai")?;
+    ///
+    ///     issue.milestone_id(milestone);
+    ///     issue.url()
+    /// }
+    /// ```
+    #[cfg(feature = "api")]
+    pub async fn resolve_milestone(
+        repository_name: &str,
+        repository_owner: &str,
+        name: &str,
+        token: Option<&str>,
+    ) -> Result<u64> {
+        validate_repository(repository_name, repository_owner)?;
+
+        api::resolve_milestone(repository_name, repository_owner, name, token).await
+    }
+
+    /// Prefilled issue title.
+    ///
+    /// Replaces any title previously set. An empty title removes the title
+    /// param, so the URL doesn't include an empty `title=`.
+    pub fn title(&mut self, title: impl Into<Cow<'a, str>>) {
+        let title = title.into();
+
+        if title.is_empty() {
+            return self.remove_param("title");
+        }
+
+        self.set_param("title", title);
+    }
+
+    /// Prefilled issue title, shortened to at most `max` chars.
+    ///
+    /// Titles longer than `max` are cut on a char boundary and `…` is
+    /// appended, keeping the title within `max` chars including the
+    /// ellipsis. Use `TITLE_MAX_LENGTH` to fit the title within the limit
+    /// GitHub applies.
+    pub fn title_truncated(&mut self, title: &'a str, max: usize) {
+        self.title(truncated_title(title, max));
+    }
+
+    /// The name of the issue template to use when opening the final link.
+    /// An issue template lives in .github/ISSUE_TEMPLATE/<issue template name>.md,
+    /// if the template you want to use when opening this link is ISSUE_TEMPLATE/bugs.md
+    /// the value for `Issue.template` must be `bugs.md`.
+    ///
+    /// Replaces any template previously set.
+    pub fn template(&mut self, template: impl Into<Cow<'a, str>>) {
+        self.set_param("template", template);
+    }
+
+    /// The issue template to use, refer to `Template` for common template
+    /// file names and to `Issue::template` for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::{Issue, Template};
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.template_kind(Template::BugReport);
+    ///
+    /// assert_eq!(issue.param_value("template"), Some("bug_report.md"));
+    /// ```
+    pub fn template_kind(&mut self, template: Template<'a>) {
+        self.template(template.file_name());
+    }
+
+    /// Checks that the template set with `Issue::template` exists in the
+    /// `.github/ISSUE_TEMPLATE` directory of the local checkout at
+    /// `repo_root`, to catch typos in template file names.
+    ///
+    /// Returns `Error::TemplateNotFound` with the template name if the file
+    /// doesn't exist. Succeeds if no template is set.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn validate_template_exists(&self, repo_root: &Path) -> Result<()> {
+        match self.param_value("template") {
+            Some(template) if !template_path(repo_root, template).is_file() => {
+                Err(Error::TemplateNotFound(template.to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Host to build the issue URL for, useful when targeting a GitHub
+    /// Enterprise Server instance such as `https://github.mycorp.com`.
+    ///
+    /// The base URL must have either the `https` or the `http` scheme and
+    /// may include a port, such as `https://git.internal:8443`. Otherwise, or
+    /// if it includes a path, query or fragment, `Error::InvalidBaseUrl` is
+    /// returned.
+    ///
+    /// When not provided, `https://github.com` is used.
+    pub fn with_base_url(&mut self, base_url: &str) -> Result<()> {
+        self.repo.with_base_url(base_url)
+    }
+
+    /// Maximum length in bytes of the URL returned by `Issue::url_checked`.
+    ///
+    /// GitHub fails to open prefilled issue URLs longer than roughly 8 KB,
+    /// `Issue::MAX_URL_LENGTH` is used by default.
+    pub fn url_length_limit(&mut self, limit: usize) {
+        self.options.url_length_limit = limit;
+    }
+
+    /// Shortens the body in the generated URL so the URL length in bytes
+    /// doesn't exceed `max_url_len`.
+    ///
+    /// When `Issue::url` is called and the URL is too long, the body is cut
+    /// on a UTF-8 char boundary and `…[truncated]` is appended to it. Other
+    /// params are never shortened, if the URL is still too long with an
+    /// empty body, the body only holds `…[truncated]`.
+    ///
+    /// The body stored in the `Issue` is left untouched, only the body
+    /// included in the generated URL is shortened.
+    pub fn truncate_body_to_fit(&mut self, max_url_len: usize) {
+        self.options.truncate_body_to_fit = Some(max_url_len);
+    }
+
+    /// Emits query params in a fixed order regardless of the order in which
+    /// setters are called, making generated URLs stable for tests.
+    ///
+    /// Params are sorted as follows: `title`, `body`, `labels`, `assignee`,
+    /// `assignees`, `milestone`, `projects`, `template` and then any other
+    /// param alphabetically by key. Params sharing the same key keep the order
+    /// in which they were set.
+    pub fn sort_params(&mut self) {
+        self.options.sort_params = true;
+    }
+
+    /// Uses the first non-empty line of the body as title when building the
+    /// URL, if no title is set.
+    ///
+    /// The line is trimmed and shortened to 80 chars, ending with `…` when
+    /// longer. A title set with `Issue::title` always wins, and no title is
+    /// used if the body is empty or unset.
+    pub fn derive_title_from_body(&mut self) {
+        self.options.derive_title = true;
+    }
+
+    /// Whether the issue author has write access to the repository, `true` by
+    /// default.
+    ///
+    /// GitHub ignores `assignee`, `assignees`, `labels`, `milestone` and
+    /// `projects` when the issue author lacks write access. When set to
+    /// `false`, those params are left out of the URL built by `Issue::url`,
+    /// and `Issue::url_checked` returns `Error::RequiresWriteAccess` instead.
+    pub fn has_write_access(&mut self, has_write_access: bool) {
+        self.options.has_write_access = has_write_access;
+    }
+
+    /// Targets the template chooser (`/issues/new/choose`) instead of the
+    /// "New Issue" form, so users pick one of the repository issue templates.
+    ///
+    /// GitHub doesn't prefill any field from the chooser page, so every param
+    /// is left out of the URL while the chooser is used. Params are still kept
+    /// in the `Issue` and returned by `Issue::params`.
+    pub fn use_template_chooser(&mut self) {
+        self.options.template_chooser = true;
+    }
+
+    /// Path within the repository to append the query params to, instead of
+    /// `issues/new`.
+    ///
+    /// This is a low-level escape hatch to reuse the param encoding for other
+    /// GitHub pages following the same prefill pattern. No check is made on
+    /// whether the page reads the params set. Leading and trailing slashes
+    /// are ignored, and each path segment is percent-encoded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.path("/discussions/new");
+    /// issue.title("Support GitLab");
+    ///
+    /// assert_eq!(
+    ///     issue.url().unwrap(),
+    ///     "https://github.com/EstebanBorai/github-issue-url/discussions/new?title=Support+GitLab"
+    /// );
+    /// ```
+    pub fn path(&mut self, path: impl Into<Cow<'a, str>>) {
+        self.options.path = Some(path.into().trim_matches('/').to_string());
+    }
+
+    /// Whether `\r\n` and lone `\r` newlines in the body are converted to
+    /// `\n` when building the URL, enabled by default.
+    ///
+    /// Bodies read from files written on Windows include `\r\n` newlines,
+    /// which GitHub may render as stray characters. Disable it to keep the
+    /// body as is.
+    pub fn normalize_newlines(&mut self, normalize: bool) {
+        self.options.normalize_newlines = normalize;
+    }
+
+    /// Removes ANSI escape sequences, such as the `\x1b[31m` color codes
+    /// found in terminal output, from the body when building the URL.
+    ///
+    /// CSI sequences (`ESC [ ... <final byte>`), OSC sequences such as
+    /// terminal hyperlinks (`ESC ] ... BEL`) and two-byte escape sequences are
+    /// removed. The body returned by `Issue::param_value` is kept as is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.body("\x1b[1;31merror\x1b[0m: build failed");
+    /// issue.strip_ansi();
+    ///
+    /// assert_eq!(
+    ///     issue.url().unwrap(),
+    ///     "https://github.com/EstebanBorai/github-issue-url/issues/new?body=error%3A+build+failed"
+    /// );
+    /// ```
+    pub fn strip_ansi(&mut self) {
+        self.options.strip_ansi = true;
+    }
+
+    /// Sets how spaces are encoded in the URL query, `SpaceEncoding::Plus`
+    /// is used by default. Refer to `SpaceEncoding` for details.
+    pub fn space_encoding(&mut self, space_encoding: SpaceEncoding) {
+        self.options.space_encoding = space_encoding;
+    }
+
+    /// Sets how the URL query is percent-encoded, `EncodingProfile::Strict`
+    /// is used by default. Refer to `EncodingProfile` for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::{EncodingProfile, Issue};
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.title("Crash (again)!");
+    /// issue.encoding_profile(EncodingProfile::GitHubCompatible);
+    ///
+    /// assert_eq!(
+    ///     issue.url().unwrap(),
+    ///     "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Crash%20(again)!"
+    /// );
+    /// ```
+    pub fn encoding_profile(&mut self, encoding_profile: EncodingProfile) {
+        self.options.encoding_profile = encoding_profile;
+    }
+
+    /// The "New Issue" URL without query params, such as
+    /// `https://github.com/<owner>/<repository>/issues/new`.
+    ///
+    /// Uses the host set with `Issue::with_base_url` if any and ends with
+    /// `issues/new/choose` when `Issue::use_template_chooser` is set, or with
+    /// the path set with `Issue::path`. `Issue::url` appends the query params
+    /// to this URL.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.title("Null: The Billion Dollar Mistake");
+    ///
+    /// assert_eq!(
+    ///     issue.new_issue_path(),
+    ///     "https://github.com/EstebanBorai/github-issue-url/issues/new"
+    /// );
+    /// ```
+    pub fn new_issue_path(&self) -> String {
+        let mut url = String::new();
+
+        self.write_new_issue_path(&mut url);
+        url
+    }
+
+    /// Builds the issue URL.
+    ///
+    /// Building the URL doesn't fail, the `Result` is kept so the signature
+    /// stays the same as in previous versions, where it could fail with
+    /// `Error::UrlParseError`. The same goes for `Issue::write_url` and
+    /// `Issue::url_parsed`.
+    #[must_use = "building the URL has no effect unless it is used"]
+    pub fn url(&self) -> Result<String> {
+        let mut url = String::new();
+
+        self.write_url(&mut url)?;
+        Ok(url)
+    }
+
+    /// Builds the same URL returned by `Issue::url`, borrowing it instead of
+    /// allocating a new `String` when there is no query to append.
+    ///
+    /// The "New Issue" URL of the repository is kept up to date by its
+    /// `RepoRef`, so `Cow::Borrowed` is returned when no param ends up in the
+    /// query and neither `Issue::path` nor `Issue::use_template_chooser` are
+    /// set. `Cow::Owned` is returned otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// assert!(matches!(
+    ///     issue.try_url().unwrap(),
+    ///     Cow::Borrowed("https://github.com/EstebanBorai/github-issue-url/issues/new")
+    /// ));
+    ///
+    /// issue.title("Null: The Billion Dollar Mistake");
+    ///
+    /// assert!(matches!(issue.try_url().unwrap(), Cow::Owned(_)));
+    /// ```
+    #[must_use = "building the URL has no effect unless it is used"]
+    pub fn try_url(&self) -> Result<Cow<'_, str>> {
+        let custom_path = self.options.path.is_some() || self.options.template_chooser;
+
+        if custom_path || !self.url_params(0, false).is_empty() {
+            return self.url().map(Cow::Owned);
+        }
+
+        self.warn_ignored_params();
+
+        Ok(Cow::Borrowed(self.repo.new_issue_url()))
+    }
+
+    /// Writes the URL returned by `Issue::url` into `buf`, clearing it first,
+    /// so the same buffer can be reused across many issues.
+    ///
+    /// The base URL, path and percent-encoded query are written straight into
+    /// `buf`, which only grows if it can't hold the URL. `Issue::url` writes
+    /// into a new `String` through this method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut buf = String::new();
+    ///
+    /// for title in ["Null pointer", "Stack overflow"] {
+    ///     let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    ///     issue.title(title);
+    ///     issue.write_url(&mut buf).unwrap();
+    ///
+    ///     assert_eq!(buf, issue.url().unwrap());
+    /// }
+    /// ```
+    pub fn write_url(&self, buf: &mut String) -> Result<()> {
+        self.warn_ignored_params();
+        self.write_url_with_order(buf, false);
+
+        Ok(())
+    }
+
+    /// Writes the URL into `buf`, clearing it first. Query params are sorted
+    /// by key and value when `sorted` is set. Refer to `Issue::cache_key`.
+    fn write_url_with_order(&self, buf: &mut String, sorted: bool) {
+        buf.clear();
+        self.write_new_issue_path(buf);
+
+        let params = self.url_params(buf.len(), sorted);
+
+        if !params.is_empty() {
+            buf.push('?');
+            self.write_query(buf, &params);
+        }
+    }
+
+    /// Builds the issue URL same as `Issue::url` but returns the `url::Url`
+    /// instead of a `String`, so it can be further manipulated without being
+    /// parsed again.
+    ///
+    /// The path is appended to the base URL segment by segment and the query
+    /// is set as written by `Issue::write_url`, so no URL string is parsed.
+    pub fn url_parsed(&self) -> Result<Url> {
+        self.warn_ignored_params();
+
+        let mut url = self.repo.parsed_url(self.new_issue_subpath());
+        let params = self.url_params(url.as_str().len(), false);
+        let mut query = String::new();
+
+        self.write_query(&mut query, &params);
+        set_query(&mut url, &query);
+
+        Ok(url)
+    }
+
+    /// The percent-encoded query of the URL returned by `Issue::url`, without
+    /// the leading `?`, to append to a "New Issue" URL built elsewhere.
+    ///
+    /// Returns an empty string when no param is set.
+    pub fn to_query_string(&self) -> Result<String> {
+        self.warn_ignored_params();
+
+        let params = self.url_params(self.new_issue_path().len(), false);
+        let mut query = String::new();
+
+        self.write_query(&mut query, &params);
+
+        Ok(query)
+    }
+
+    /// Iterates the key/value pairs of the URL query, percent-encoded exactly
+    /// as they appear in the URL returned by `Issue::url`.
+    ///
+    /// Pairs reflect every option set on the `Issue`, such as
+    /// `Issue::sort_params`, `Issue::space_encoding` or
+    /// `Issue::truncate_body_to_fit`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.labels("bug,production");
+    /// issue.title("Null: The Billion Dollar Mistake");
+    /// issue.sort_params();
+    ///
+    /// let pairs: Vec<(String, String)> = issue.encoded_pairs().unwrap().collect();
+    ///
+    /// assert_eq!(
+    ///     pairs,
+    ///     vec![
+    ///         (String::from("title"), String::from("Null%3A+The+Billion+Dollar+Mistake")),
+    ///         (String::from("labels"), String::from("bug%2Cproduction")),
+    ///     ]
+    /// );
+    /// ```
+    pub fn encoded_pairs(&self) -> Result<impl Iterator<Item = (String, String)>> {
+        self.warn_ignored_params();
+
+        let params = self.url_params(self.new_issue_path().len(), false);
+        let pairs: Vec<(String, String)> = params
+            .iter()
+            .map(|(key, value)| (self.encoded(key), self.encoded(value)))
+            .collect();
+
+        Ok(pairs.into_iter())
+    }
+
+    /// Iterates the key/value pairs currently set, borrowed as is without
+    /// percent-encoding, in the same order they were set.
+    ///
+    /// Unlike `Issue::encoded_pairs`, this doesn't build the URL, so options
+    /// applied when building it, such as `Issue::with_footer` or
+    /// `Issue::sort_params`, aren't reflected. Use it to read back what was
+    /// set without the cost of encoding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.title("Null: The Billion Dollar Mistake");
+    /// issue.labels("bug,production");
+    ///
+    /// let pairs: Vec<(&str, &str)> = issue.query_pairs().collect();
+    ///
+    /// assert_eq!(
+    ///     pairs,
+    ///     vec![
+    ///         ("title", "Null: The Billion Dollar Mistake"),
+    ///         ("labels", "bug,production"),
+    ///     ]
+    /// );
+    /// ```
+    pub fn query_pairs(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.params
+            .iter()
+            .map(|(key, value)| (*key, value.as_ref()))
+    }
+
+    /// Builds the issue URL same as `Issue::url`, along with whether its
+    /// length in bytes exceeds the limit set with `Issue::url_length_limit`,
+    /// which defaults to `Issue::MAX_URL_LENGTH`.
+    ///
+    /// Useful to show the URL along with a warning when it's too long,
+    /// instead of failing as `Issue::url_checked` does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.title("Null: The Billion Dollar Mistake");
+    /// issue.url_length_limit(64);
+    ///
+    /// let (url, too_long) = issue.url_with_length_flag().unwrap();
+    ///
+    /// assert_eq!(url, issue.url().unwrap());
+    /// assert!(too_long);
+    /// ```
+    #[must_use = "building the URL has no effect unless it is used"]
+    pub fn url_with_length_flag(&self) -> Result<(String, bool)> {
+        let url = self.url()?;
+        let too_long = url.len() > self.options.url_length_limit;
+
+        Ok((url, too_long))
+    }
+
+    /// Builds the issue URL same as `Issue::url` but returns
+    /// `Error::UrlTooLong` if the URL length in bytes exceeds the limit set
+    /// with `Issue::url_length_limit`, which defaults to
+    /// `Issue::MAX_URL_LENGTH`.
+    ///
+    /// Returns `Error::RequiresWriteAccess` if params requiring write access
+    /// are set after calling `Issue::has_write_access` with `false`.
+    #[must_use = "building the URL has no effect unless it is used"]
+    pub fn url_checked(&self) -> Result<String> {
+        if let Some(key) = self.write_access_params().next() {
+            return Err(Error::RequiresWriteAccess(key.to_string()));
+        }
+
+        self.url_within_limit()
+    }
+
+    /// Builds the issue URL checking it doesn't exceed the length limit
+    fn url_within_limit(&self) -> Result<String> {
+        let url = self.url()?;
+        let limit = self.options.url_length_limit;
+
+        if url.len() > limit {
+            return Err(Error::UrlTooLong {
+                length: url.len(),
+                limit,
+            });
+        }
+
+        Ok(url)
+    }
+
+    /// Checks the issue for every known problem at once, instead of failing
+    /// on the first one as `Issue::url_checked` does.
+    ///
+    /// The following conditions are reported:
+    ///
+    /// - `Error::UnknownParam` for each param other than the ones with a
+    ///   setter when no template is set, as only issue forms read such params
+    /// - `Error::DuplicateParam` for each single-valued param (`title`,
+    ///   `body`, `assignee`, `milestone` and `template`) set more than once.
+    ///   Setters such as `Issue::title` replace the current value, so this
+    ///   only happens when the param is pushed again through
+    ///   `Issue::custom_param`, `Issue::append_param` or a field `id` passed
+    ///   to `Issue::form_fields`, or when parsing a URL repeating it with
+    ///   `Issue::from_url` or `Issue::from_parsed_url`
+    /// - `Error::TitleTooLong` if the title exceeds `TITLE_MAX_LENGTH` chars
+    /// - `Error::RequiresWriteAccess` for each param requiring write access
+    ///   set after calling `Issue::has_write_access` with `false`
+    /// - `Error::UrlParseError` if the URL can't be built, or
+    ///   `Error::UrlTooLong` if it exceeds the limit set with
+    ///   `Issue::url_length_limit`
+    pub fn validate(&self) -> core::result::Result<(), Vec<Error>> {
+        let mut errors = Vec::new();
+        let has_template = self.param_value("template").is_some();
+
+        for (index, (key, _)) in self.params.iter().enumerate() {
+            if !self.is_param_enabled(key) {
+                continue;
+            }
+
+            let first = self.params.iter().position(|(param, _)| param == key) == Some(index);
+
+            if !first {
+                if SINGLE_VALUED_PARAMS.contains(key) {
+                    errors.push(Error::DuplicateParam(key.to_string()));
+                }
+
+                continue;
+            }
+
+            if !has_template && !CANONICAL_PARAMS_ORDER.contains(key) {
+                errors.push(Error::UnknownParam(key.to_string()));
+            }
+        }
+
+        if let Some(title) = self
+            .param_value("title")
+            .filter(|_| self.is_param_enabled("title"))
+        {
+            let length = title.chars().count();
+
+            if length > TITLE_MAX_LENGTH {
+                errors.push(Error::TitleTooLong {
+                    length,
+                    limit: TITLE_MAX_LENGTH,
+                });
+            }
+        }
+
+        for key in self.write_access_params() {
+            errors.push(Error::RequiresWriteAccess(key.to_string()));
+        }
+
+        if let Err(error) = self.url_within_limit() {
+            errors.push(error);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Builds the issue URL and wraps it in a markdown link with `text` as the
+    /// link text.
+    ///
+    /// Backslashes, brackets and parentheses in `text` are escaped with a
+    /// backslash, refer to `Issue::link_markdown` to link any other URL.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use github_issue_url::Issue;
+    ///
+    /// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+    ///
+    /// issue.labels("bug");
+    ///
+    /// assert_eq!(
+    ///     issue.markdown_link("Report a [bug]").unwrap(),
+    ///     "[Report a \\[bug\\]](https://github.com/EstebanBorai/github-issue-url/issues/new?labels=bug)"
+    /// );
+    /// ```
+    pub fn markdown_link(&self, text: &str) -> Result<String> {
+        Ok(markdown::link(text, &self.url()?))
+    }
+
+    /// Builds the issue URL and wraps it in an HTML `<a>` tag with `text` as
+    /// the link content, both `text` and the URL are HTML escaped.
+    ///
+    /// When `target_blank` is set, `target="_blank"` and
+    /// `rel="noopener noreferrer"` are added so the link opens in a new tab.
+    pub fn html_anchor(&self, text: &str, target_blank: bool) -> Result<String> {
+        Ok(markdown::html_anchor(text, &self.url()?, target_blank))
+    }
+
+    /// Builds the issue URL and opens it in the default browser.
+    ///
+    /// Returns `Error::BrowserLaunchFailed` if the browser couldn't be
+    /// launched.
+    ///
+    /// Requires the `open` feature.
+    #[cfg(feature = "open")]
+    pub fn open(&self) -> Result<()> {
+        let url = self.url()?;
+
+        webbrowser::open(&url).map_err(|e| Error::BrowserLaunchFailed(e.to_string()))
+    }
+
+    /// Builds a `github://` deep link which opens the "New Issue" screen in
+    /// the GitHub mobile app, such as
+    /// `github://EstebanBorai/github-issue-url/issues/new?title=Crash`.
+    ///
+    /// The app only handles `github.com` repositories, so the `https` URL
+    /// returned by `Issue::url` is used instead when another host is set with
+    /// `Issue::with_base_url`.
+    ///
+    /// The deep link format is not documented by GitHub. `title` and `body`
+    /// are known to be prefilled by the app, other params are kept in the
+    /// link but may be ignored.
+    ///
+    /// Requires the `experimental` feature.
+    #[cfg(feature = "experimental")]
+    #[must_use = "building the URL has no effect unless it is used"]
+    pub fn mobile_url(&self) -> Result<String> {
+        let url = self.url()?;
+
+        match url.strip_prefix("https://github.com/") {
+            Some(path) => Ok(format!("github://{}", path)),
+            None => Ok(url),
+        }
+    }
+
+    fn push_param(&mut self, key: &'a str, value: impl Into<Cow<'a, str>>) {
+        self.params.push((key, value.into()));
+    }
+
+    /// Pushes a param with a caller provided `key`, failing with
+    /// `Error::EmptyParamKey` if `key` is empty
+    fn push_checked_param(&mut self, key: &'a str, value: impl Into<Cow<'a, str>>) -> Result<()> {
+        if key.is_empty() {
+            return Err(Error::EmptyParamKey);
+        }
+
+        self.push_param(key, value);
+
+        Ok(())
+    }
+
+    /// Sets a single-valued param, replacing its current value in place and
+    /// dropping any other param with the same key
+    fn set_param(&mut self, key: &'a str, value: impl Into<Cow<'a, str>>) {
+        match self.params.iter().position(|(param, _)| *param == key) {
+            Some(index) => {
+                self.params[index].1 = value.into();
+
+                let mut position = 0;
+
+                self.params.retain(|(param, _)| {
+                    position += 1;
+                    *param != key || position == index + 1
+                });
+            }
+            None => self.push_param(key, value),
+        }
+    }
+
+    /// Keys of the params requiring write access when the issue author lacks
+    /// it, each key listed once
+    fn write_access_params(&self) -> impl Iterator<Item = &str> + '_ {
+        WRITE_ACCESS_PARAMS.iter().copied().filter(move |key| {
+            !self.options.has_write_access
+                && self.is_param_enabled(key)
+                && self.param_value(key).is_some()
+        })
+    }
+
+    /// Warns about every param GitHub ignores when the `log` feature is
+    /// enabled, does nothing otherwise
+    fn warn_ignored_params(&self) {
+        #[cfg(feature = "log")]
+        for (key, reason) in self.ignored_params() {
+            log::warn!("Query param \"{}\" is ignored by GitHub: {}", key, reason);
+        }
+    }
+
+    /// Params set on the `Issue` which GitHub ignores, along with the reason
+    #[cfg(feature = "log")]
+    fn ignored_params(&self) -> Vec<(&str, &'static str)> {
+        let mut ignored = Vec::new();
+        let is_form = self
+            .param_value("template")
+            .is_some_and(|template| template.ends_with(".yml") || template.ends_with(".yaml"));
+
+        for (key, _) in self
+            .params
+            .iter()
+            .filter(|(key, _)| self.is_param_enabled(key))
+        {
+            if self.options.template_chooser {
+                ignored.push((*key, "the template chooser doesn't prefill any field"));
+            } else if is_form && *key == "body" {
+                ignored.push((*key, "issue forms don't have a body field"));
+            } else if is_form && FORM_IGNORED_PARAMS.contains(key) {
+                ignored.push((*key, "issue forms don't prefill it"));
+            } else if !self.options.has_write_access && WRITE_ACCESS_PARAMS.contains(key) {
+                ignored.push((*key, "it requires write access to the repository"));
+            }
+        }
+
+        ignored
+    }
+
+    /// Params to include in the URL query, according to the `Issue` options
+    fn query_params(&self) -> Vec<(&str, Cow<'_, str>)> {
+        let mut params = self.query_params_without_footer();
+
+        self.append_footer(&mut params);
+        params
+    }
+
+    /// Params to include in the URL query, same as `Issue::query_params` but
+    /// leaving out the footer so the body can be truncated on its own
+    fn query_params_without_footer(&self) -> Vec<(&str, Cow<'_, str>)> {
+        if self.options.template_chooser {
+            return Vec::new();
+        }
+
+        let mut params = self.params.clone();
+
+        params.retain(|(key, _)| self.is_param_enabled(key));
+
+        if self.options.strip_ansi {
+            for (key, value) in params.iter_mut() {
+                if *key == "body" && value.contains('\x1b') {
+                    *value = Cow::Owned(strip_ansi_codes(value));
+                }
+            }
+        }
+
+        if self.options.derive_title && !self.contains_param("title") {
+            let title = params
+                .iter()
+                .find(|(key, _)| *key == "body")
+                .and_then(|(_, body)| body.lines().map(str::trim).find(|line| !line.is_empty()))
+                .map(|line| truncated_title(line, DERIVED_TITLE_MAX_LENGTH).into_owned());
+
+            if let Some(title) = title {
+                params.insert(0, ("title", Cow::Owned(title)));
+            }
+        }
+
+        if !self.options.has_write_access {
+            params.retain(|(key, _)| !WRITE_ACCESS_PARAMS.contains(key));
+        }
+
+        if self.options.normalize_newlines {
+            for (key, value) in params.iter_mut() {
+                if *key == "body" && value.contains('\r') {
+                    *value = Cow::Owned(normalized_newlines(value));
+                }
+            }
+        }
+
+        if self.options.sort_params {
+            params.sort_by(|(a, _), (b, _)| canonical_order(a, b));
+        }
+
+        params
+    }
+
+    /// Appends the footer set with `Issue::with_footer` to the body in
+    /// `params`, or adds it as the body if no body is set
+    fn append_footer<'s>(&'s self, params: &mut Vec<(&'s str, Cow<'s, str>)>) {
+        let footer = match self.options.footer.as_deref() {
+            Some(footer) if !self.options.template_chooser => footer,
+            _ => return,
+        };
+        let footer = if self.options.normalize_newlines && footer.contains('\r') {
+            Cow::Owned(normalized_newlines(footer))
+        } else {
+            Cow::Borrowed(footer)
+        };
+
+        match params.iter_mut().find(|(key, _)| *key == "body") {
+            Some((_, body)) => *body = Cow::Owned(format!("{}\n\n---\n\n{}", body, footer)),
+            None if self.is_param_enabled("body") => {
+                params.push(("body", footer));
+
+                if self.options.sort_params {
+                    params.sort_by(|(a, _), (b, _)| canonical_order(a, b));
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Path within the repository of the "New Issue" URL. Refer to
+    /// `Issue::new_issue_path`.
+    fn new_issue_subpath(&self) -> &str {
+        if let Some(path) = self.options.path.as_deref() {
+            path
+        } else if self.options.template_chooser {
+            "issues/new/choose"
+        } else {
+            "issues/new"
+        }
+    }
+
+    /// Writes the "New Issue" URL without query params into `buf`. Refer to
+    /// `Issue::new_issue_path`.
+    fn write_new_issue_path(&self, buf: &mut String) {
+        self.repo.write_url(buf, self.new_issue_subpath());
+    }
+
+    /// Writes `params` into `buf` as the percent-encoded URL query, without
+    /// the leading `?`, according to the `Issue` encoding options
+    fn write_query(&self, buf: &mut String, params: &[(&str, Cow<'_, str>)]) {
+        write_query(
+            buf,
+            params.iter().map(|(key, value)| (*key, value.as_ref())),
+            self.options.space_encoding,
+            self.options.encoding_profile,
+        );
+    }
+
+    /// Percent-encodes a single query key or value same as
+    /// `Issue::write_query`
+    fn encoded(&self, value: &str) -> String {
+        let mut encoded = String::new();
+
+        write_query_component(
+            &mut encoded,
+            value,
+            self.options.space_encoding,
+            self.options.encoding_profile,
+        );
+        encoded
+    }
+
+    /// Params written into the URL query, with the footer appended to the
+    /// body and sorted by key and value if `sorted` is set.
+    ///
+    /// When `Issue::truncate_body_to_fit` is set, the body is truncated so the
+    /// URL, made of `path_len` bytes before the query, fits within the limit.
+    fn url_params(&self, path_len: usize, sorted: bool) -> Vec<(&str, Cow<'_, str>)> {
+        let params = self.query_params_without_footer();
+        let mut scratch = String::new();
+        let full = self.footed_params(params.clone(), sorted);
+
+        match self.options.truncate_body_to_fit {
+            Some(max_url_len) if path_len + self.query_len(&mut scratch, &full) > max_url_len => {
+                self.params_with_truncated_body(params, path_len, max_url_len, sorted)
+            }
+            _ => full,
+        }
+    }
+
+    /// Appends the footer to the body in `params`, sorted by key and value if
+    /// `sorted` is set
+    fn footed_params<'s>(
+        &'s self,
+        mut params: Vec<(&'s str, Cow<'s, str>)>,
+        sorted: bool,
+    ) -> Vec<(&'s str, Cow<'s, str>)> {
+        self.append_footer(&mut params);
+
+        if sorted {
+            params.sort_unstable();
+        }
+
+        params
+    }
+
+    /// Length in bytes of the URL query for `params`, including the leading
+    /// `?`, encoded into `scratch` which is cleared first
+    fn query_len(&self, scratch: &mut String, params: &[(&str, Cow<'_, str>)]) -> usize {
+        if params.is_empty() {
+            return 0;
+        }
+
+        scratch.clear();
+        self.write_query(scratch, params);
+        scratch.len() + 1
+    }
+
+    /// Params with the longest body prefix which keeps the URL length within
+    /// `max_url_len`. Refer to `Issue::truncate_body_to_fit`.
+    ///
+    /// `params` must not include the footer, which is appended to every body
+    /// prefix so it's never truncated.
+    fn params_with_truncated_body<'s>(
+        &'s self,
+        mut params: Vec<(&'s str, Cow<'s, str>)>,
+        path_len: usize,
+        max_url_len: usize,
+        sorted: bool,
+    ) -> Vec<(&'s str, Cow<'s, str>)> {
+        let index = match params.iter().position(|(key, _)| *key == "body") {
+            Some(index) => index,
+            None => return self.footed_params(params, sorted),
+        };
+        let body = params[index].1.clone();
+        let boundaries: Vec<usize> = body.char_indices().map(|(i, _)| i).collect();
+        let mut scratch = String::new();
+
+        // Encoded URL length grows along with the body prefix, so the longest
+        // prefix that fits is found with a binary search over char boundaries
+        let mut low = 0;
+        let mut high = boundaries.len();
+
+        while low < high {
+            let middle = (low + high).div_ceil(2);
+
+            params[index].1 = Cow::Owned(truncated_body(&body[..boundaries[middle - 1]]));
+
+            let candidate = self.footed_params(params.clone(), sorted);
+
+            if path_len + self.query_len(&mut scratch, &candidate) <= max_url_len {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        let prefix = match low {
+            0 => "",
+            low => &body[..boundaries[low - 1]],
+        };
+
+        params[index].1 = Cow::Owned(truncated_body(prefix));
+        self.footed_params(params, sorted)
+    }
+}
+
+/// Writes the same URL returned by `Issue::url`.
+///
+/// If building the URL fails, the "New Issue" URL is written without any
+/// query params (e.g. `https://github.com/<owner>/<repository>/issues/new`),
+/// use `Issue::url` instead to handle the error.
+impl<'a> fmt::Display for Issue<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.url() {
+            Ok(url) => write!(f, "{}", url),
+            Err(_) => write!(f, "{}", self.new_issue_path()),
+        }
+    }
+}
+
+/// Lists params sorted by key and value, regardless of the order they were
+/// set in, so the output is stable for snapshot tests. Use `{:#?}` to print
+/// one param per line.
+impl<'a> fmt::Debug for Issue<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Issue")
+            .field("repository_owner", &self.repo.owner())
+            .field("repository_name", &self.repo.repo())
+            .field("base_url", &self.repo.base_url())
+            .field("params", &sorted_params(&self.params))
+            .field("options", &self.options)
+            .finish()
+    }
+}
+
+/// Creates an `Issue` from a `(repository name, repository owner)` tuple, same
+/// as `Issue::new`.
+impl<'a> TryFrom<(&'a str, &'a str)> for Issue<'a> {
+    type Error = Error;
+
+    fn try_from((repository_name, repository_owner): (&'a str, &'a str)) -> Result<Self> {
+        Issue::new(repository_name, repository_owner)
+    }
+}
+
+/// Creates an `Issue` from an `<owner>/<repository>` slug, same as
+/// `Issue::from_slug`.
+///
+/// # Example
+///
+/// ```
+/// use std::convert::TryInto;
+///
+/// use github_issue_url::Issue;
+///
+/// let issue: Issue = "EstebanBorai/github-issue-url".try_into().unwrap();
+///
+/// assert_eq!(
+///     issue.url().unwrap(),
+///     "https://github.com/EstebanBorai/github-issue-url/issues/new"
+/// );
+/// ```
+impl<'a> TryFrom<&'a str> for Issue<'a> {
+    type Error = Error;
+
+    fn try_from(slug: &'a str) -> Result<Self> {
+        Issue::from_slug(slug)
+    }
+}
+
+/// Settings used when building the issue URL, shared by `Issue` and
+/// `IssueOwned`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub(crate) struct Options {
+    url_length_limit: usize,
+    truncate_body_to_fit: Option<usize>,
+    sort_params: bool,
+    space_encoding: SpaceEncoding,
+    encoding_profile: EncodingProfile,
+    normalize_newlines: bool,
+    template_chooser: bool,
+    has_write_access: bool,
+    derive_title: bool,
+    disabled_params: Vec<String>,
+    strip_ansi: bool,
+    footer: Option<String>,
+    path: Option<String>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            url_length_limit: Issue::MAX_URL_LENGTH,
+            truncate_body_to_fit: None,
+            sort_params: false,
+            space_encoding: SpaceEncoding::Plus,
+            encoding_profile: EncodingProfile::Strict,
+            normalize_newlines: true,
+            template_chooser: false,
+            has_write_access: true,
+            derive_title: false,
+            disabled_params: Vec::new(),
+            strip_ansi: false,
+            footer: None,
+            path: None,
+        }
+    }
+}
+
+impl Options {
+    fn disable_param(&mut self, key: &str) {
+        if self.is_param_enabled(key) {
+            self.disabled_params.push(key.to_string());
+        }
+    }
+
+    fn enable_param(&mut self, key: &str) {
+        self.disabled_params.retain(|param| param != key);
+    }
+
+    fn is_param_enabled(&self, key: &str) -> bool {
+        !self.disabled_params.iter().any(|param| param == key)
+    }
+}
+
+/// Removes CSI, OSC and two-byte ANSI escape sequences from `text`
+fn strip_ansi_codes(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            stripped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            // Parameter and intermediate bytes up to a final byte in `@..=~`
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // Terminated by BEL or by ST (`ESC \`)
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+
+                    if c == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            Some(c) if ('0'..='~').contains(&c) => {}
+            Some(c) => stripped.push(c),
+            None => {}
+        }
+    }
+
+    stripped
+}
+
+/// Path to the `template` issue template within the repository at `repo_root`
+#[cfg(feature = "std")]
+fn template_path(repo_root: &Path, template: &str) -> std::path::PathBuf {
+    repo_root
+        .join(".github")
+        .join("ISSUE_TEMPLATE")
+        .join(template)
+}
+
+/// Name to display for the `key` param, capitalized if it's a known param
+fn field_name(key: &str) -> String {
+    let mut chars = key.chars();
+
+    match chars.next() {
+        Some(first) if CANONICAL_PARAMS_ORDER.contains(&key) => {
+            first.to_uppercase().chain(chars).collect()
+        }
+        _ => key.to_string(),
+    }
+}
+
+/// Compares param keys according to `CANONICAL_PARAMS_ORDER`
+fn canonical_order(a: &str, b: &str) -> Ordering {
+    let rank = |key: &str| {
+        CANONICAL_PARAMS_ORDER
+            .iter()
+            .position(|canonical| *canonical == key)
+            .unwrap_or(CANONICAL_PARAMS_ORDER.len())
+    };
+
+    rank(a).cmp(&rank(b)).then_with(|| {
+        if rank(a) == CANONICAL_PARAMS_ORDER.len() {
+            a.cmp(b)
+        } else {
+            Ordering::Equal
+        }
+    })
+}
+
+/// Shortens `title` to at most `max` chars, ending with
+/// `TRUNCATED_TITLE_SUFFIX` when shortened
+pub(crate) fn truncated_title(title: &str, max: usize) -> Cow<'_, str> {
+    match title.char_indices().nth(max) {
+        None => Cow::Borrowed(title),
+        Some(_) if max == 0 => Cow::Borrowed(""),
+        Some(_) => {
+            let end = title.char_indices().nth(max - 1).map_or(0, |(i, _)| i);
+
+            Cow::Owned(format!("{}{}", &title[..end], TRUNCATED_TITLE_SUFFIX))
+        }
+    }
+}
+
+/// Inserts a `<keyword> #<number>` line after the references at the start of
+/// `body`, or returns `None` if `body` already includes it
+pub(crate) fn referenced_body(body: &str, keyword: Reference, number: u64) -> Option<String> {
+    let reference = format!("{} #{}", keyword.as_str(), number);
+
+    if body.lines().any(|line| line.trim_end() == reference) {
+        return None;
+    }
+
+    let references_len: usize = body
+        .split_inclusive('\n')
+        .take_while(|line| is_reference(line.trim_end()))
+        .map(str::len)
+        .sum();
+    let (references, rest) = body.split_at(references_len);
+    let rest = rest.trim_start_matches(['\r', '\n']);
+    let mut body = String::from(references);
+
+    if !body.is_empty() && !body.ends_with('\n') {
+        body.push('\n');
+    }
+
+    body.push_str(&reference);
+
+    if !rest.is_empty() {
+        body.push_str("\n\n");
+        body.push_str(rest);
+    }
+
+    Some(body)
+}
+
+/// Whether `line` is a reference such as `Closes #123`
+fn is_reference(line: &str) -> bool {
+    Reference::ALL.iter().any(|keyword| {
+        line.strip_prefix(keyword.as_str())
+            .and_then(|line| line.strip_prefix(" #"))
+            .is_some_and(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+    })
+}
+
+/// Params sorted by key and value, to compare them regardless of order
+fn sorted_params<'s>(params: &'s [(&str, Cow<'_, str>)]) -> Vec<(&'s str, &'s str)> {
+    let mut params: Vec<(&str, &str)> = params
+        .iter()
+        .map(|(key, value)| (*key, value.as_ref()))
+        .collect();
+
+    params.sort_unstable();
+    params
+}
+
+/// Replaces `\r\n` and lone `\r` line endings with `\n`
+fn normalized_newlines(value: &str) -> String {
+    value.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// Appends the truncation mark to a body prefix
+fn truncated_body(prefix: &str) -> String {
+    format!("{}{}", prefix, TRUNCATED_BODY_SUFFIX)
+}
+
+/// Checks both repository name and repository owner are defined and follow
+/// GitHub naming rules
+fn validate_repository(repository_name: &str, repository_owner: &str) -> Result<()> {
+    if repository_name.is_empty() {
+        return Err(Error::EmptyRepositoryName);
+    }
+
+    if repository_owner.is_empty() {
+        return Err(Error::EmptyRepositoryOwner);
+    }
+
+    if !is_valid_repository_owner(repository_owner) {
+        return Err(Error::InvalidRepositoryOwner(repository_owner.to_string()));
+    }
+
+    if !is_valid_repository_name(repository_name) {
+        return Err(Error::InvalidRepositoryName(repository_name.to_string()));
+    }
+
+    Ok(())
+}
+
+/// GitHub usernames and organization names have up to 39 alphanumeric
+/// characters or single hyphens, and can't begin or end with a hyphen
+fn is_valid_repository_owner(repository_owner: &str) -> bool {
+    repository_owner.len() <= 39
+        && !repository_owner.starts_with('-')
+        && !repository_owner.ends_with('-')
+        && !repository_owner.contains("--")
+        && repository_owner
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+/// GitHub repository names have up to 100 alphanumeric characters, hyphens,
+/// underscores or periods, and can't be `.` or `..`
+fn is_valid_repository_name(repository_name: &str) -> bool {
+    repository_name.len() <= 100
+        && repository_name != "."
+        && repository_name != ".."
+        && repository_name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// Joins labels with comma (`,`), failing if any label includes a comma
+pub(crate) fn join_labels<S: AsRef<str>>(labels: &[S]) -> Result<String> {
+    let labels: Vec<&str> = labels.iter().map(AsRef::as_ref).collect();
+
+    if let Some(label) = labels.iter().find(|label| label.contains(',')) {
+        return Err(Error::InvalidLabel(label.to_string()));
+    }
+
+    Ok(labels.join(","))
+}
+
+/// Joins IDs with comma (`,`)
+pub(crate) fn join_ids(ids: &[u64]) -> String {
+    let ids: Vec<String> = ids.iter().map(u64::to_string).collect();
+
+    ids.join(",")
+}
+
+/// Builds `<base url>/<owner>/<repository>/<path>`, using `github.com` when
+/// no base URL is provided.
+///
+/// Owner and repository are percent-encoded as a single path segment each,
+/// `path` is split on slashes (`/`) and each of its segments is
+/// percent-encoded too.
+pub(crate) fn repository_url(
+    base_url: Option<&Url>,
+    repository_owner: &str,
+    repository_name: &str,
+    path: &str,
+) -> String {
+    let mut url = String::new();
+
+    write_repository_url(&mut url, base_url, repository_owner, repository_name, path);
+    url
+}
+
+/// Writes the URL returned by `repository_url` into `buf`
+pub(crate) fn write_repository_url(
+    buf: &mut String,
+    base_url: Option<&Url>,
+    repository_owner: &str,
+    repository_name: &str,
+    path: &str,
+) {
+    let segments = iter::once(repository_owner)
+        .chain(iter::once(repository_name))
+        .chain(path.split('/'));
+
+    write_url_with_path(buf, base_url, GITHUB_BASE_URL, segments);
+}
+
+/// Builds the same URL as `repository_url` as a `url::Url`
+pub(crate) fn parsed_repository_url(
+    base_url: Option<&Url>,
+    repository_owner: &str,
+    repository_name: &str,
+    path: &str,
+) -> Url {
+    let segments = iter::once(repository_owner)
+        .chain(iter::once(repository_name))
+        .chain(path.split('/'));
+
+    parsed_url_with_path(base_url, GITHUB_BASE_URL, segments)
+}
+
+/// Appends the percent-encoded path `segments` to `base_url`, or to
+/// `default_base_url` when no base URL is provided
+pub(crate) fn url_with_path<'s>(
+    base_url: Option<&Url>,
+    default_base_url: &str,
+    segments: impl IntoIterator<Item = &'s str>,
+) -> String {
+    let mut url = String::new();
+
+    write_url_with_path(&mut url, base_url, default_base_url, segments);
+    url
+}
+
+/// Writes the URL returned by `url_with_path` into `buf`.
+///
+/// Segments are encoded the same as with `Url::path_segments_mut`: tabs and
+/// newlines are removed and `.` or `..` segments are left out.
+fn write_url_with_path<'s>(
+    buf: &mut String,
+    base_url: Option<&Url>,
+    default_base_url: &str,
+    segments: impl IntoIterator<Item = &'s str>,
+) {
+    // Base URLs only hold an origin, which `Url` serializes with a trailing
+    // slash
+    buf.push_str(
+        base_url
+            .map_or(default_base_url, Url::as_str)
+            .trim_end_matches('/'),
+    );
+
+    for segment in segments {
+        if segment == "." || segment == ".." {
+            continue;
+        }
+
+        buf.push('/');
+
+        for part in segment.split(['\t', '\n', '\r']) {
+            buf.extend(utf8_percent_encode(part, PATH_SEGMENT));
+        }
+    }
+}
+
+/// Builds the same URL as `url_with_path` as a `url::Url`, appending the
+/// `segments` with `Url::path_segments_mut` instead of parsing a string
+pub(crate) fn parsed_url_with_path<'s>(
+    base_url: Option<&Url>,
+    default_base_url: &str,
+    segments: impl IntoIterator<Item = &'s str>,
+) -> Url {
+    let mut url = match base_url {
+        Some(base_url) => base_url.clone(),
+        None => Url::parse(default_base_url).expect("default base URLs are valid"),
+    };
+
+    // Base URLs are either `http` or `https` URLs, which always have path
+    // segments
+    if let Ok(mut path) = url.path_segments_mut() {
+        path.pop_if_empty().extend(segments);
+    }
+
+    url
+}
+
+/// Appends `params` to `url` as the URL query encoded with `write_query`
+/// default encoding, leaving out the `?` when there are no params
+pub(crate) fn url_with_params(mut url: String, params: &[(&str, Cow<'_, str>)]) -> String {
+    if !params.is_empty() {
+        url.push('?');
+        write_query(
+            &mut url,
+            params.iter().map(|(key, value)| (*key, value.as_ref())),
+            SpaceEncoding::Plus,
+            EncodingProfile::Strict,
+        );
+    }
+
+    url
+}
+
+/// Sets `params` as the query of `url`, same as `url_with_params` does
+pub(crate) fn parsed_url_with_params(mut url: Url, params: &[(&str, Cow<'_, str>)]) -> Url {
+    let mut query = String::new();
+
+    write_query(
+        &mut query,
+        params.iter().map(|(key, value)| (*key, value.as_ref())),
+        SpaceEncoding::Plus,
+        EncodingProfile::Strict,
+    );
+    set_query(&mut url, &query);
+    url
+}
+
+/// Sets the already encoded `query` on `url`, removing the query if empty
+fn set_query(url: &mut Url, query: &str) {
+    url.set_query(Some(query).filter(|query| !query.is_empty()));
+}
+
+/// Writes `params` into `buf` as the percent-encoded URL query, without the
+/// leading `?`.
+///
+/// Every builder encodes its query through this function. Keys and values
+/// are form-urlencoded, refer to `write_query_component`.
+pub(crate) fn write_query<'p>(
+    buf: &mut String,
+    params: impl IntoIterator<Item = (&'p str, &'p str)>,
+    space_encoding: SpaceEncoding,
+    encoding_profile: EncodingProfile,
+) {
+    for (index, (key, value)) in params.into_iter().enumerate() {
+        if index > 0 {
+            buf.push('&');
+        }
+
+        write_query_component(buf, key, space_encoding, encoding_profile);
+        buf.push('=');
+        write_query_component(buf, value, space_encoding, encoding_profile);
+    }
+}
+
+/// Writes the form-urlencoded `value` into `buf`.
+///
+/// Form-urlencoding writes each space as a lone `+` chunk, which is encoded
+/// according to `space_encoding` instead. With
+/// `EncodingProfile::GitHubCompatible`, spaces are always encoded as `%20`
+/// and the chars `encodeURIComponent` leaves as is are unescaped, except for
+/// `'` which the WHATWG URL standard escapes in `http` and `https` queries.
+fn write_query_component(
+    buf: &mut String,
+    value: &str,
+    space_encoding: SpaceEncoding,
+    encoding_profile: EncodingProfile,
+) {
+    let github_compatible = encoding_profile == EncodingProfile::GitHubCompatible;
+    let percent_spaces = github_compatible || space_encoding == SpaceEncoding::Percent;
+
+    for chunk in url::form_urlencoded::byte_serialize(value.as_bytes()) {
+        let chunk = match chunk {
+            "+" if percent_spaces => "%20",
+            "%21" if github_compatible => "!",
+            "%28" if github_compatible => "(",
+            "%29" if github_compatible => ")",
+            "%7E" if github_compatible => "~",
+            chunk => chunk,
+        };
+
+        buf.push_str(chunk);
+    }
+}
+
+/// Parses a base URL making sure it uses either `https` or `http` scheme and
+/// only holds an origin, which keeps the port if any
+pub(crate) fn parse_base_url(base_url: &str) -> Result<Url> {
+    let url = Url::parse(base_url).map_err(|e| Error::InvalidBaseUrl(e.to_string()))?;
+
+    if url.scheme() != "https" && url.scheme() != "http" {
+        return Err(Error::InvalidBaseUrl(format!(
+            "Unsupported scheme \"{}\", expected \"https\" or \"http\"",
+            url.scheme()
+        )));
+    }
+
+    if url.path() != "/" || url.query().is_some() || url.fragment().is_some() {
+        return Err(Error::InvalidBaseUrl(format!(
+            "Base URL \"{}\" must not include a path, query or fragment",
+            base_url
+        )));
+    }
+
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GITHUB_ISSUE_LINK: &str = "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake&body=Null+is+a+flag.+It+represents+different+situations+depending+on+the+context+in+which+it+is+used+and+invoked.+This+yields+the+most+serious+error+in+software+development%3A+Coupling+a+hidden+decision+in+the+contract+between+an+object+and+who+uses+it.&template=bug_report.md&labels=bug%2Cproduction%2Chigh-severity&assignee=EstebanBorai&milestone=1&projects=1";
+    const SAMPLE_ISSUE_BODY: &str = r#"Null is a flag. It represents different situations depending on the context in which it is used and invoked. This yields the most serious error in software development: Coupling a hidden decision in the contract between an object and who uses it."#;
+
+    #[test]
+    fn build_issue_url() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Null: The Billion Dollar Mistake");
+        have.body(SAMPLE_ISSUE_BODY);
+        have.template("bug_report.md");
+        have.labels("bug,production,high-severity");
+        have.assignee("EstebanBorai");
+        have.milestone("1");
+        have.projects("1");
+
+        let have = have.url().unwrap();
+
+        assert_eq!(have, GITHUB_ISSUE_LINK.to_string());
+    }
+
+    #[test]
+    fn build_issue_url_with_base_url() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.with_base_url("https://github.mycorp.com").unwrap();
+        have.title("Null: The Billion Dollar Mistake");
+
+        let have = have.url().unwrap();
+
+        assert_eq!(
+            have,
+            "https://github.mycorp.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake"
+        );
+    }
+
+    #[test]
+    fn return_error_if_base_url_is_invalid() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        assert!(matches!(
+            have.with_base_url("github.mycorp.com"),
+            Err(Error::InvalidBaseUrl(_))
+        ));
+        assert!(matches!(
+            have.with_base_url("ftp://github.mycorp.com"),
+            Err(Error::InvalidBaseUrl(_))
+        ));
+        assert!(matches!(
+            have.with_base_url("https://github.mycorp.com/github"),
+            Err(Error::InvalidBaseUrl(_))
+        ));
+        assert!(matches!(
+            have.with_base_url("https://github.mycorp.com/?tab=issues"),
+            Err(Error::InvalidBaseUrl(_))
+        ));
+    }
+
+    #[test]
+    fn build_issue_url_with_base_url_port() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.with_base_url("http://localhost:3000").unwrap();
+        have.labels("bug");
+
+        assert_eq!(
+            have.url().unwrap(),
+            "http://localhost:3000/EstebanBorai/github-issue-url/issues/new?labels=bug"
+        );
+
+        have.with_base_url("https://git.internal:8443/").unwrap();
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://git.internal:8443/EstebanBorai/github-issue-url/issues/new?labels=bug"
+        );
+    }
+
+    #[test]
+    fn build_issue_url_with_assignees() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.assignees(&["alice", "bob"]);
+
+        let have = have.url().unwrap();
+
+        assert_eq!(
+            have,
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?assignees=alice&assignees=bob"
+        );
+    }
+
+    #[test]
+    fn build_issue_url_with_labels_slice() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.labels_slice(&["bug", "production", "high severity"])
+            .unwrap();
+
+        let have = have.url().unwrap();
+
+        assert_eq!(
+            have,
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?labels=bug%2Cproduction%2Chigh+severity"
+        );
+    }
+
+    #[test]
+    fn return_error_if_label_contains_comma() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        assert!(matches!(
+            have.labels_slice(&["bug", "production,high-severity"]),
+            Err(Error::InvalidLabel(label)) if label == "production,high-severity"
+        ));
+    }
+
+    #[test]
+    fn encode_query_same_as_encode_uri_component() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Don't panic! (v1.0 ~ v2.0) *beta*");
+        have.body("a+b=c & d/e? 🐛");
+        have.encoding_profile(EncodingProfile::GitHubCompatible);
+
+        let url = have.url().unwrap();
+
+        // Query values as encoded by `encodeURIComponent`, except for `'`
+        assert_eq!(
+            url,
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Don%27t%20panic!%20(v1.0%20~%20v2.0)%20*beta*&body=a%2Bb%3Dc%20%26%20d%2Fe%3F%20%F0%9F%90%9B"
+        );
+        assert_eq!(
+            Issue::from_url(&url).unwrap().param_value("title"),
+            Some("Don't panic! (v1.0 ~ v2.0) *beta*")
+        );
+    }
+
+    #[test]
+    fn summarize_prefilled_fields() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.body("Null is a flag.");
+        have.derive_title_from_body();
+        have.projects_slice(&[1, 2]);
+        have.custom_param("version", "1.2.3").unwrap();
+        have.milestone("1");
+        have.disable_param("milestone");
+
+        assert_eq!(
+            have.prefilled_fields(),
+            vec![
+                (String::from("Title"), String::from("Null is a flag.")),
+                (String::from("Body"), String::from("Null is a flag.")),
+                (String::from("Projects"), String::from("1, 2")),
+                (String::from("version"), String::from("1.2.3")),
+            ]
+        );
+    }
+
+    #[test]
+    fn encode_unicode_title_and_body() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("🐛 Crash on startup");
+        have.body("Ça plante: 起動時にクラッシュ 👩‍💻");
+
+        let url = have.url().unwrap();
+
+        assert_eq!(
+            url,
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=%F0%9F%90%9B+Crash+on+startup&body=%C3%87a+plante%3A+%E8%B5%B7%E5%8B%95%E6%99%82%E3%81%AB%E3%82%AF%E3%83%A9%E3%83%83%E3%82%B7%E3%83%A5+%F0%9F%91%A9%E2%80%8D%F0%9F%92%BB"
+        );
+
+        let parsed = Issue::from_url(&url).unwrap();
+
+        assert_eq!(parsed.param_value("title"), Some("🐛 Crash on startup"));
+        assert_eq!(
+            parsed.param_value("body"),
+            Some("Ça plante: 起動時にクラッシュ 👩‍💻")
+        );
+    }
+
+    #[test]
+    fn truncate_unicode_title_by_chars() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title_truncated("🐛🐛🐛 起動時にクラッシュ", 5);
+
+        assert_eq!(have.param_value("title"), Some("🐛🐛🐛 …"));
+
+        have.title("🐛".repeat(TITLE_MAX_LENGTH));
+
+        assert!(have.validate().is_ok());
+    }
+
+    #[test]
+    fn encode_special_chars_in_labels() {
+        let want = "https://github.com/EstebanBorai/github-issue-url/issues/new?labels=C%23%2CQ%26A%2Cgood+first+issue";
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.labels_slice(&["C#", "Q&A", "good first issue"])
+            .unwrap();
+
+        assert_eq!(have.url().unwrap(), want);
+
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.labels("C#,Q&A,good first issue");
+
+        assert_eq!(have.url().unwrap(), want);
+        assert_eq!(
+            Issue::from_url(want).unwrap().param_value("labels"),
+            Some("C#,Q&A,good first issue")
+        );
+    }
+
+    #[test]
+    fn build_issue_url_with_milestone_id() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.milestone_id(42);
+
+        let have = have.url().unwrap();
+
+        assert_eq!(
+            have,
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?milestone=42"
+        );
+    }
+
+    #[test]
+    fn build_issue_url_with_custom_params() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.custom_param("browser", "Firefox").unwrap();
+        have.custom_param("version", "1.2.3").unwrap();
+
+        let have = have.url().unwrap();
+
+        assert_eq!(
+            have,
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?browser=Firefox&version=1.2.3"
+        );
+    }
+
+    #[test]
+    fn return_error_if_custom_param_key_is_empty() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        assert!(matches!(
+            have.custom_param("", "Firefox"),
+            Err(Error::EmptyParamKey)
+        ));
+    }
+
+    #[test]
+    fn return_error_if_url_is_too_long() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.body(SAMPLE_ISSUE_BODY);
+
+        assert!(have.url_checked().is_ok());
+
+        have.url_length_limit(100);
+
+        assert!(matches!(
+            have.url_checked(),
+            Err(Error::UrlTooLong { length, limit: 100 }) if length == have.url().unwrap().len()
+        ));
+    }
+
+    #[test]
+    fn return_error_if_url_exceeds_max_url_length() {
+        let body = "a".repeat(Issue::MAX_URL_LENGTH);
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.body(&body);
+
+        assert!(matches!(
+            have.url_checked(),
+            Err(Error::UrlTooLong {
+                limit: Issue::MAX_URL_LENGTH,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn truncate_body_to_fit_url_length() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Null: The Billion Dollar Mistake");
+        have.body("Null is a flag. ¿Qué es Null?");
+        have.labels("bug");
+        have.truncate_body_to_fit(150);
+
+        let have = have.url().unwrap();
+
+        assert!(have.len() <= 150);
+        assert_eq!(
+            have,
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake&body=Null+is+a%E2%80%A6%5Btruncated%5D&labels=bug"
+        );
+    }
+
+    #[test]
+    fn truncate_body_on_char_boundary() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.body("¿¿¿¿¿¿¿¿¿¿");
+        have.truncate_body_to_fit(120);
+
+        let have = Url::parse(&have.url().unwrap()).unwrap();
+        let (_, body) = have.query_pairs().find(|(key, _)| key == "body").unwrap();
+
+        assert_eq!(body, "¿¿¿¿¿…[truncated]");
+    }
+
+    #[test]
+    fn keep_footer_whole_when_truncating_body() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.body(SAMPLE_ISSUE_BODY);
+        have.with_footer(String::from("_Filed by MyApp v1.2.3_"));
+        have.truncate_body_to_fit(200);
+
+        let url = have.url().unwrap();
+        let url = Url::parse(&url).unwrap();
+        let (_, body) = url.query_pairs().find(|(key, _)| key == "body").unwrap();
+
+        assert!(url.as_str().len() <= 200);
+        assert!(body.starts_with("Null is a flag."));
+        assert!(body.ends_with("…[truncated]\n\n---\n\n_Filed by MyApp v1.2.3_"));
+        assert_eq!(have.param_value("body"), Some(SAMPLE_ISSUE_BODY));
+    }
+
+    #[test]
+    fn keep_body_if_url_fits() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.body(SAMPLE_ISSUE_BODY);
+
+        let want = have.url().unwrap();
+
+        have.truncate_body_to_fit(want.len());
+
+        assert_eq!(have.url().unwrap(), want);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn build_issue_from_panic_info() {
+        use std::panic;
+        use std::sync::{Arc, Mutex};
+
+        let issue = Arc::new(Mutex::new(None));
+        let hook_issue = Arc::clone(&issue);
+        let default_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            *hook_issue.lock().unwrap() =
+                Some(Issue::from_panic_info("github-issue-url", "EstebanBorai", info).unwrap());
+        }));
+
+        let line = line!() + 1;
+        let _ = panic::catch_unwind(|| panic!("Null & \"undefined\"\nare not the same"));
+
+        panic::set_hook(default_hook);
+
+        let have = issue.lock().unwrap().take().unwrap();
+        let mut want = IssueOwned::new("github-issue-url", "EstebanBorai").unwrap();
+
+        want.title("Null & \"undefined\"\nare not the same");
+        want.body(format!("```\nsrc/lib.rs:{}:40\n```", line));
+
+        assert_eq!(have, want);
+        assert!(have.url().unwrap().contains(
+            "?title=Null+%26+%22undefined%22%0Aare+not+the+same&body=%60%60%60%0Asrc%2Flib.rs%3A"
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn validate_template_exists_in_repository() {
+        let path = std::env::temp_dir().join("github-issue-url-validate-template");
+        let templates = path.join(".github").join("ISSUE_TEMPLATE");
+
+        std::fs::create_dir_all(&templates).unwrap();
+        std::fs::write(templates.join("bug_report.md"), "").unwrap();
+
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        assert!(have.validate_template_exists(&path).is_ok());
+
+        have.template("bug_report.md");
+
+        assert!(have.validate_template_exists(&path).is_ok());
+
+        have.template("bug.md");
+
+        assert!(matches!(
+            have.validate_template_exists(&path),
+            Err(Error::TemplateNotFound(template)) if template == "bug.md"
+        ));
+    }
+
+    #[test]
+    fn set_params_from_owned_and_borrowed_strings() {
+        let title = String::from("Null: The Billion Dollar Mistake");
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title(&title);
+        have.body(format!("{} is a flag.", "Null"));
+        have.labels(Cow::Borrowed("bug"));
+        have.custom_param("version", String::from("1.2.3")).unwrap();
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake&body=Null+is+a+flag.&labels=bug&version=1.2.3"
+        );
+    }
+
+    #[test]
+    fn debug_params_regardless_of_order() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+        let mut want = have.clone();
+
+        have.title("Null");
+        have.labels("bug");
+        want.labels("bug");
+        want.title("Null");
+
+        assert_ne!(have, want);
+        assert_eq!(format!("{:#?}", have), format!("{:#?}", want));
+        assert!(format!("{:#?}", have).contains(
+            "params: [\n        (\n            \"labels\",\n            \"bug\",\n        ),\n        (\n            \"title\","
+        ));
+    }
+
+    #[test]
+    fn flag_url_exceeding_length_limit() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Null");
+
+        let (url, too_long) = have.url_with_length_flag().unwrap();
+
+        assert!(!too_long);
+
+        have.url_length_limit(url.len());
+
+        assert_eq!(have.url_with_length_flag().unwrap(), (url.clone(), false));
+
+        have.url_length_limit(url.len() - 1);
+
+        assert_eq!(have.url_with_length_flag().unwrap(), (url, true));
+    }
+
+    #[test]
+    fn assign_to_self_replaces_assignee() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.assignee("EstebanBorai");
+        have.assign_to_self();
+
+        assert_eq!(have.param_value("assignee"), Some("@me"));
+        assert_eq!(
+            Issue::from_url(&have.url().unwrap())
+                .unwrap()
+                .param_value("assignee"),
+            Some("@me")
+        );
+    }
+
+    #[test]
+    fn build_issue_for_crate() {
+        let have = issue_for_crate!().unwrap();
+
+        assert_eq!(
+            have,
+            IssueOwned::new("github-issue-url", "EstebanBorai").unwrap()
+        );
+        assert!(matches!(
+            Issue::__from_crate_repository("https://gitlab.com/EstebanBorai/github-issue-url"),
+            Err(Error::RemoteParseFailed(_))
+        ));
+        assert!(matches!(
+            Issue::__from_crate_repository(""),
+            Err(Error::RemoteParseFailed(_))
+        ));
+    }
+
+    #[cfg(feature = "cargo")]
+    #[test]
+    fn build_issue_from_cargo_manifest() {
+        let path = std::env::temp_dir().join("github-issue-url-from-cargo-manifest.toml");
+        let have = Issue::from_cargo_manifest(Path::new("Cargo.toml")).unwrap();
+        let want = IssueOwned::new("github-issue-url", "EstebanBorai").unwrap();
+
+        assert_eq!(have, want);
+
+        std::fs::write(&path, "[package]\nname = \"github-issue-url\"\n").unwrap();
+
+        assert!(matches!(
+            Issue::from_cargo_manifest(&path),
+            Err(Error::ManifestParse(_))
+        ));
+
+        std::fs::write(&path, "[package\n").unwrap();
+
+        assert!(matches!(
+            Issue::from_cargo_manifest(&path),
+            Err(Error::ManifestParse(_))
+        ));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "git")]
+    #[test]
+    fn build_issue_from_local_git() {
+        let path = std::env::temp_dir().join("github-issue-url-from-local-git");
+        let _ = std::fs::remove_dir_all(&path);
+        let repository = git2::Repository::init(&path).unwrap();
+
+        repository
+            .remote("origin", "git@github.com:EstebanBorai/github-issue-url.git")
+            .unwrap();
+
+        let have = Issue::from_local_git(&path).unwrap();
+        let want = IssueOwned::new("github-issue-url", "EstebanBorai").unwrap();
+
+        assert_eq!(have, want);
+
+        repository.remote_set_url("origin", "/dev/null").unwrap();
+
+        assert!(matches!(
+            Issue::from_local_git(&path),
+            Err(Error::RemoteParseFailed(_))
+        ));
+
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn sort_params_in_canonical_order() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.custom_param("version", "1.2.3").unwrap();
+        have.projects("1");
+        have.milestone("1");
+        have.assignee("EstebanBorai");
+        have.custom_param("browser", "Firefox").unwrap();
+        have.labels("bug,production,high-severity");
+        have.template("bug_report.md");
+        have.body(SAMPLE_ISSUE_BODY);
+        have.title("Null: The Billion Dollar Mistake");
+        have.sort_params();
+
+        let have = have.url().unwrap();
+
+        assert_eq!(
+            have,
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake&body=Null+is+a+flag.+It+represents+different+situations+depending+on+the+context+in+which+it+is+used+and+invoked.+This+yields+the+most+serious+error+in+software+development%3A+Coupling+a+hidden+decision+in+the+contract+between+an+object+and+who+uses+it.&labels=bug%2Cproduction%2Chigh-severity&assignee=EstebanBorai&milestone=1&projects=1&template=bug_report.md&browser=Firefox&version=1.2.3"
+        );
+    }
+
+    #[test]
+    fn build_parsed_issue_url() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Null: The Billion Dollar Mistake");
+
+        let mut have = have.url_parsed().unwrap();
+
+        assert_eq!(have.host_str(), Some("github.com"));
+
+        have.set_fragment(Some("top"));
+
+        assert_eq!(
+            have.as_str(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake#top"
+        );
+    }
+
+    #[test]
+    fn read_and_remove_params() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Null: The Billion Dollar Mistake");
+        have.assignees(&["alice", "bob"]);
+        have.milestone_id(1);
+
+        assert_eq!(
+            have.params(),
+            &[
+                ("title", Cow::from("Null: The Billion Dollar Mistake")),
+                ("assignees", Cow::from("alice")),
+                ("assignees", Cow::from("bob")),
+                ("milestone", Cow::from("1")),
+            ]
+        );
+
+        have.remove_param("assignees");
+
+        assert_eq!(
+            have.params(),
+            &[
+                ("title", Cow::from("Null: The Billion Dollar Mistake")),
+                ("milestone", Cow::from("1")),
+            ]
+        );
+
+        have.clear_params();
+
+        assert!(have.params().is_empty());
+    }
+
+    #[test]
+    fn replace_single_valued_params() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Null");
+        have.body("Null is a flag.");
+        have.milestone("1");
+        have.title("Null: The Billion Dollar Mistake");
+        have.body("Null is not a flag.");
+        have.milestone_id(2);
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake&body=Null+is+not+a+flag.&milestone=2"
+        );
+    }
+
+    #[test]
+    fn build_issue_url_with_percent_encoded_spaces() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Null: The Billion Dollar Mistake");
+        have.body("1 + 1");
+        have.space_encoding(SpaceEncoding::Percent);
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A%20The%20Billion%20Dollar%20Mistake&body=1%20%2B%201"
+        );
+
+        have.space_encoding(SpaceEncoding::Plus);
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake&body=1+%2B+1"
+        );
+    }
+
+    #[test]
+    fn build_issue_url_with_normalized_newlines() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.body("OS: windows\r\nVersion: 1.2.3\rArch: x86_64");
+
+        let url = have.url().unwrap();
+
+        assert!(!url.contains("%0D"));
+        assert!(url.ends_with("body=OS%3A+windows%0AVersion%3A+1.2.3%0AArch%3A+x86_64"));
+
+        have.normalize_newlines(false);
+
+        assert!(have
+            .url()
+            .unwrap()
+            .ends_with("body=OS%3A+windows%0D%0AVersion%3A+1.2.3%0DArch%3A+x86_64"));
+    }
+
+    #[test]
+    fn truncate_long_titles() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+        let title = "ñ".repeat(300);
+
+        have.title_truncated(&title, TITLE_MAX_LENGTH);
+
+        let title = have.param_value("title").unwrap();
+
+        assert_eq!(title.chars().count(), TITLE_MAX_LENGTH);
+        assert!(title.ends_with('…'));
+
+        have.title_truncated("Null: The Billion Dollar Mistake", TITLE_MAX_LENGTH);
+
+        assert_eq!(
+            have.param_value("title"),
+            Some("Null: The Billion Dollar Mistake")
+        );
+
+        have.title_truncated("Null", 3);
+
+        assert_eq!(have.param_value("title"), Some("Nu…"));
+    }
+
+    #[test]
+    fn build_issue_url_on_top_of_new_issue_path() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.with_base_url("https://github.mycorp.com/").unwrap();
+
+        assert_eq!(
+            have.new_issue_path(),
+            "https://github.mycorp.com/EstebanBorai/github-issue-url/issues/new"
+        );
+        assert_eq!(have.url().unwrap(), have.new_issue_path());
+
+        have.labels("bug");
+
+        assert_eq!(
+            have.url().unwrap(),
+            format!("{}?labels=bug", have.new_issue_path())
+        );
+    }
+
+    #[test]
+    fn create_issue_with_try_from() {
+        assert_eq!(
+            Issue::try_from(("github-issue-url", "EstebanBorai")).unwrap(),
+            Issue::new("github-issue-url", "EstebanBorai").unwrap()
+        );
+        assert_eq!(
+            Issue::try_from("EstebanBorai/github-issue-url").unwrap(),
+            Issue::new("github-issue-url", "EstebanBorai").unwrap()
+        );
+        assert!(matches!(
+            Issue::try_from(("", "EstebanBorai")),
+            Err(Error::EmptyRepositoryName)
+        ));
+        assert!(matches!(
+            Issue::try_from("EstebanBorai"),
+            Err(Error::InvalidSlug(_))
+        ));
+    }
+
+    #[test]
+    fn build_issue_url_with_projects_slice() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.projects_slice(&[1, 22, 333]);
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?projects=1%2C22%2C333"
+        );
+    }
+
+    #[test]
+    fn build_template_chooser_url_without_params() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Null: The Billion Dollar Mistake");
+        have.body("Null is a flag.");
+        have.use_template_chooser();
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new/choose"
+        );
+        assert_eq!(have.params().len(), 2);
+    }
+
+    #[test]
+    fn equal_issues_have_equal_hashes() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashSet;
+        use std::hash::{Hash, Hasher};
+
+        let hash = |issue: &Issue| {
+            let mut hasher = DefaultHasher::new();
+
+            issue.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let mut a = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        a.with_base_url("https://github.mycorp.com").unwrap();
+        a.title("Null: The Billion Dollar Mistake");
+
+        let b = a.clone();
+        let mut c = a.clone();
+
+        c.body("Null is a flag.");
+
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+        assert_eq!(vec![a, b, c].into_iter().collect::<HashSet<_>>().len(), 2);
+    }
+
+    #[test]
+    fn validate_reports_every_problem() {
+        let title = "a".repeat(300);
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title(&title);
+        have.custom_param("browser", "Firefox").unwrap();
+        have.custom_param("title", "Null").unwrap();
+        have.url_length_limit(100);
+
+        let errors = have.validate().unwrap_err();
+
+        assert_eq!(errors.len(), 4);
+        assert!(matches!(&errors[0], Error::UnknownParam(key) if key == "browser"));
+        assert!(matches!(&errors[1], Error::DuplicateParam(key) if key == "title"));
+        assert!(matches!(
+            errors[2],
+            Error::TitleTooLong {
+                length: 300,
+                limit: TITLE_MAX_LENGTH
+            }
+        ));
+        assert!(matches!(errors[3], Error::UrlTooLong { limit: 100, .. }));
+
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.template("bug_report.yml");
+        have.custom_param("browser", "Firefox").unwrap();
+
+        assert!(have.validate().is_ok());
+
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.body("Null is a flag.");
+        have.append_param("body", "Null is a flag.").unwrap();
+        have.append_param("labels", "bug").unwrap();
+        have.append_param("labels", "production").unwrap();
+
+        let errors = have.validate().unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(&errors[0], Error::DuplicateParam(key) if key == "body"));
+
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.form_fields("bug_report.yml", &[("template", "feature_request.yml")])
+            .unwrap();
+
+        assert!(matches!(
+            have.validate().unwrap_err().as_slice(),
+            [Error::DuplicateParam(key)] if key == "template"
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn read_body_from_file() {
+        let path = std::env::temp_dir().join("github-issue-url-body_from_file.md");
+
+        std::fs::write(&path, "OS: windows\r\nVersion: 1.2.3\r\n").unwrap();
+
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.body("Null is a flag.");
+        have.body_from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?body=OS%3A+windows%0AVersion%3A+1.2.3%0A"
+        );
+        assert!(matches!(have.body_from_file(&path), Err(Error::Io(_))));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn leave_out_empty_body_and_title_from_any_source() {
+        let path = std::env::temp_dir().join("github-issue-url-empty-body.md");
+
+        std::fs::write(&path, "").unwrap();
+
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+        let mut owned = IssueOwned::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.body("Null is a flag.");
+        have.body_from_file(&path).unwrap();
+        owned.body_from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(!have.contains_param("body"));
+
+        have.body("Null is a flag.");
+        have.body_from_env_template("").unwrap();
+        owned.body_from_env_template("").unwrap();
+        have.title("Null: The Billion Dollar Mistake");
+        have.title_truncated("", 10);
+        owned.title_truncated("", 10);
+
+        assert!(!have.has_params());
+        assert!(!owned.has_params());
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new"
+        );
+    }
+
+    #[test]
+    fn return_query_string() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        assert_eq!(have.to_query_string().unwrap(), "");
+
+        have.title("Null: The Billion Dollar Mistake");
+        have.body("Null is a flag.\n1 + 1");
+        have.labels("bug,production");
+
+        let query = have.to_query_string().unwrap();
+
+        assert_eq!(
+            query,
+            "title=Null%3A+The+Billion+Dollar+Mistake&body=Null+is+a+flag.%0A1+%2B+1&labels=bug%2Cproduction"
+        );
+        assert_eq!(
+            format!("{}?{}", have.new_issue_path(), query),
+            have.url().unwrap()
+        );
+
+        let pairs = url::form_urlencoded::parse(query.as_bytes());
+        let round_trip = Url::parse_with_params(&have.new_issue_path(), pairs).unwrap();
+
+        assert_eq!(String::from(round_trip), have.url().unwrap());
+    }
+
+    #[test]
+    fn iterate_encoded_pairs() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.body("1 + 1");
+        have.title("a");
+        have.title("b");
+        have.space_encoding(SpaceEncoding::Percent);
+
+        let have: Vec<(String, String)> = have.encoded_pairs().unwrap().collect();
+
+        assert_eq!(
+            have,
+            vec![
+                (String::from("body"), String::from("1%20%2B%201")),
+                (String::from("title"), String::from("b")),
+            ]
+        );
+        assert_eq!(
+            Issue::new("github-issue-url", "EstebanBorai")
+                .unwrap()
+                .encoded_pairs()
+                .unwrap()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn iterate_unencoded_query_pairs() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.body("1 + 1");
+        have.assignees(&["EstebanBorai", "octocat"]);
+        have.with_footer("Sent from MyApp");
+
+        let owned = IssueOwned::from(&have);
+
+        assert_eq!(
+            have.query_pairs().collect::<Vec<_>>(),
+            vec![
+                ("body", "1 + 1"),
+                ("assignees", "EstebanBorai"),
+                ("assignees", "octocat"),
+            ]
+        );
+        assert!(owned.query_pairs().eq(have.query_pairs()));
+    }
+
+    #[test]
+    fn leave_out_write_access_params_without_write_access() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Null: The Billion Dollar Mistake");
+        have.labels("bug");
+        have.assignee("EstebanBorai");
+
+        assert!(have.url_checked().is_ok());
+
+        have.has_write_access(false);
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake"
+        );
+        assert!(matches!(
+            have.url_checked(),
+            Err(Error::RequiresWriteAccess(key)) if key == "assignee"
+        ));
+
+        let errors = have.validate().unwrap_err();
+
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(&errors[1], Error::RequiresWriteAccess(key) if key == "labels"));
+    }
+
+    #[test]
+    fn reset_issue_to_another_repository() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.with_base_url("https://github.mycorp.com").unwrap();
+        have.title("Null: The Billion Dollar Mistake");
+
+        let capacity = have.params.capacity();
+
+        have.reset_to("rust", "rust-lang").unwrap();
+        have.labels("bug");
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.mycorp.com/rust-lang/rust/issues/new?labels=bug"
+        );
+        assert_eq!(have.params.capacity(), capacity);
+        assert!(matches!(
+            have.reset_to("", "rust-lang"),
+            Err(Error::EmptyRepositoryName)
+        ));
+        assert_eq!(
+            have.new_issue_path(),
+            "https://github.mycorp.com/rust-lang/rust/issues/new"
+        );
+    }
+
+    #[test]
+    fn count_params() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        assert_eq!(have.param_count(), 0);
+        assert!(!have.has_params());
+
+        have.title("Null: The Billion Dollar Mistake");
+        have.assignees(&["alice", "bob"]);
+
+        assert_eq!(have.param_count(), 3);
+        assert!(have.has_params());
+    }
+
+    #[test]
+    fn query_param_state() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Null: The Billion Dollar Mistake");
+        have.assignees(&["alice", "bob"]);
+
+        assert!(have.contains_param("title"));
+        assert!(have.contains_param("assignees"));
+        assert!(!have.contains_param("body"));
+        assert_eq!(
+            have.param_value("title"),
+            Some("Null: The Billion Dollar Mistake")
+        );
+        assert_eq!(have.param_value("assignees"), Some("alice"));
+        assert_eq!(have.param_value("body"), None);
+    }
+
+    #[test]
+    fn return_error_if_form_field_id_is_empty() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        assert!(matches!(
+            have.form_fields("bug_report.yml", &[("version", "1.2.3"), ("", "Firefox")]),
+            Err(Error::EmptyParamKey)
+        ));
+        assert!(have.params().is_empty());
+    }
+
+    #[test]
+    fn percent_encode_repository_path_segments() {
+        let mut have = Issue::new_unchecked("github issue#url", "Esteban Borai");
+
+        have.labels("bug");
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/Esteban%20Borai/github%20issue%23url/issues/new?labels=bug"
+        );
+        assert!(matches!(
+            Issue::new("github-issue-url", "Esteban Borai"),
+            Err(Error::InvalidRepositoryOwner(_))
+        ));
+    }
+
+    #[test]
+    fn derive_title_from_first_body_line() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.derive_title_from_body();
+
+        assert_eq!(have.url().unwrap(), have.new_issue_path());
+
+        have.body("\n  \nthread 'main' panicked\nat src/main.rs:1:1");
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=thread+%27main%27+panicked&body=%0A++%0Athread+%27main%27+panicked%0Aat+src%2Fmain.rs%3A1%3A1"
+        );
+
+        let body = "a".repeat(100);
+
+        have.body(&body);
+
+        let title: Vec<(String, String)> = have.encoded_pairs().unwrap().take(1).collect();
+
+        assert_eq!(title[0].1, format!("{}%E2%80%A6", "a".repeat(79)));
+
+        have.title("Null: The Billion Dollar Mistake");
+
+        assert!(have.url().unwrap().contains("?body=aaa"));
+    }
+
+    #[test]
+    fn omit_empty_title_and_body() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("");
+        have.body("");
+        have.append_body("");
+
+        assert_eq!(have.url().unwrap(), have.new_issue_path());
+
+        have.title("Null: The Billion Dollar Mistake");
+        have.body("Null is a flag.");
+        have.title("");
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?body=Null+is+a+flag."
+        );
+    }
+
+    #[test]
+    fn compare_issues_ignoring_params_order() {
+        let mut a = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+        let mut b = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        a.title("Null: The Billion Dollar Mistake");
+        a.assignees(&["alice", "bob"]);
+        b.assignees(&["bob"]);
+        b.title("Null: The Billion Dollar Mistake");
+        b.assignees(&["alice"]);
+
+        assert_ne!(a, b);
+        assert!(a.semantically_eq(&b));
+
+        b.assignees(&["alice"]);
+
+        assert!(!a.semantically_eq(&b));
+    }
+
+    #[test]
+    fn share_cache_key_regardless_of_params_order() {
+        let mut a = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+        let mut b = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        a.title("a b&c");
+        a.assignees(&["alice", "bob"]);
+        b.assignees(&["bob"]);
+        b.title("a b&c");
+        b.assignees(&["alice"]);
+
+        assert_ne!(a.url().unwrap(), b.url().unwrap());
+        assert_eq!(a.cache_key(), b.cache_key());
+        assert_eq!(IssueOwned::from(&a).cache_key(), b.cache_key());
+        assert_eq!(
+            a.cache_key(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?assignees=alice&assignees=bob&title=a+b%26c"
+        );
+
+        b.assignees(&["alice"]);
+
+        assert_ne!(a.cache_key(), b.cache_key());
+
+        a.assignees(&["alice"]);
+
+        assert_eq!(a.cache_key(), b.cache_key());
+
+        a.disable_param("labels");
+
+        assert_eq!(a.cache_key(), b.cache_key());
+    }
+
+    #[test]
+    fn change_cache_key_with_options_changing_the_url() {
+        let mut base = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        base.title("a b");
+        base.body("Null is a flag.");
+
+        let options: [fn(&mut Issue); 8] = [
+            |issue| issue.space_encoding(SpaceEncoding::Percent),
+            |issue| issue.use_template_chooser(),
+            |issue| issue.with_footer("Sent from MyApp"),
+            |issue| issue.disable_param("body"),
+            |issue| issue.path("issues/new/bug"),
+            |issue| issue.truncate_body_to_fit(80),
+            |issue| issue.encoding_profile(EncodingProfile::GitHubCompatible),
+            |issue| issue.with_base_url("https://github.mycorp.com").unwrap(),
+        ];
+
+        for option in options {
+            let mut have = base.clone();
+
+            option(&mut have);
+
+            assert_ne!(have.url().unwrap(), base.url().unwrap());
+            assert_ne!(have.cache_key(), base.cache_key());
+        }
+    }
+
+    #[test]
+    fn build_issue_url_with_template_kind() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.template_kind(Template::FeatureRequest);
+
+        assert_eq!(have.param_value("template"), Some("feature_request.md"));
+
+        have.template_kind(Template::Custom("bug_report.yml"));
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?template=bug_report.yml"
+        );
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn report_nothing_ignored_with_markdown_template_and_write_access() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Null: The Billion Dollar Mistake");
+        have.body("Null is a flag.");
+        have.milestone("1");
+        have.assignee("EstebanBorai");
+
+        assert_eq!(have.ignored_params(), vec![]);
+
+        have.template("bug_report.md");
+
+        assert_eq!(have.ignored_params(), vec![]);
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn report_params_ignored_by_issue_forms() {
+        for template in ["bug_report.yml", "bug_report.yaml"] {
+            let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+            have.template(template);
+            have.title("Null: The Billion Dollar Mistake");
+            have.body("Null is a flag.");
+            have.milestone("1");
+            have.projects("1");
+
+            assert_eq!(
+                have.ignored_params(),
+                vec![
+                    ("body", "issue forms don't have a body field"),
+                    ("milestone", "issue forms don't prefill it"),
+                    ("projects", "issue forms don't prefill it"),
+                ]
+            );
+        }
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn report_params_requiring_write_access() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Null: The Billion Dollar Mistake");
+        have.milestone("1");
+        have.assignee("EstebanBorai");
+        have.has_write_access(false);
+
+        assert_eq!(
+            have.ignored_params(),
+            vec![
+                ("milestone", "it requires write access to the repository"),
+                ("assignee", "it requires write access to the repository"),
+            ]
+        );
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn report_params_ignored_by_template_chooser() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Null: The Billion Dollar Mistake");
+        have.body("Null is a flag.");
+        have.use_template_chooser();
+
+        assert_eq!(
+            have.ignored_params(),
+            vec![
+                ("title", "the template chooser doesn't prefill any field"),
+                ("body", "the template chooser doesn't prefill any field"),
+            ]
+        );
+    }
+
+    #[test]
+    fn build_url_for_custom_path() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.use_template_chooser();
+        have.path("issues/new/choose/");
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new/choose"
+        );
+
+        have.path(format!("{}/new", "saved replies"));
+
+        assert_eq!(
+            have.new_issue_path(),
+            "https://github.com/EstebanBorai/github-issue-url/saved%20replies/new"
+        );
+    }
+
+    #[test]
+    fn use_footer_as_body_if_no_body_is_set() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Null: The Billion Dollar Mistake");
+        have.with_footer("_Filed by MyApp_");
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake&body=_Filed+by+MyApp_"
+        );
+
+        have.disable_param("body");
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake"
+        );
+    }
+
+    #[test]
+    fn strip_ansi_codes_from_body() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.body("\x1b[1m\x1b[38;5;196mpanicked\x1b[0m at [src/main.rs]\n\x1b]8;;https://example.com\x07link\x1b]8;;\x1b\\ \x1b7done\x1b");
+        have.strip_ansi();
+        have.derive_title_from_body();
+
+        assert_eq!(
+            have.query_params(),
+            vec![
+                ("title", Cow::Borrowed("panicked at [src/main.rs]")),
+                (
+                    "body",
+                    Cow::Borrowed("panicked at [src/main.rs]\nlink done")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn leave_disabled_params_out_of_url() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.body("Null is a flag.");
+        have.derive_title_from_body();
+        have.has_write_access(false);
+        have.labels("bug");
+        have.disable_param("body");
+        have.disable_param("labels");
+
+        assert_eq!(
+            have.url_checked().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new"
+        );
+
+        have.enable_param("body");
+
+        assert!(have.is_param_enabled("body"));
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null+is+a+flag.&body=Null+is+a+flag."
+        );
+    }
+
+    #[test]
+    fn write_url_into_buffer() {
+        let mut buf = String::with_capacity(1024);
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        buf.push_str(SAMPLE_ISSUE_BODY);
+
+        let capacity = buf.capacity();
+
+        have.title("Null: The Billion Dollar Mistake");
+        have.body("Spaces & ampersands");
+        have.write_url(&mut buf).unwrap();
+
+        assert_eq!(buf, have.url().unwrap());
+
+        have.title("Short");
+        have.body(SAMPLE_ISSUE_BODY);
+        have.sort_params();
+        have.truncate_body_to_fit(150);
+        have.write_url(&mut buf).unwrap();
+
+        assert_eq!(buf, have.url().unwrap());
+        assert!(buf.len() <= 150);
+        assert_eq!(buf.capacity(), capacity);
+    }
+
+    #[test]
+    fn encode_path_segments_same_as_url() {
+        let segments = [
+            "a b", "a%b", "a\\b", "a/b", "¿", "a\tb", ".", "..", "", "a;b@c",
+        ];
+
+        for segment in segments {
+            let mut want = Url::parse(GITHUB_BASE_URL).unwrap();
+
+            want.path_segments_mut()
+                .unwrap()
+                .pop_if_empty()
+                .extend(["owner", segment, "issues"]);
+
+            assert_eq!(
+                url_with_path(None, GITHUB_BASE_URL, ["owner", segment, "issues"]),
+                want.as_str()
+            );
+            assert_eq!(
+                parsed_url_with_path(None, GITHUB_BASE_URL, ["owner", segment, "issues"]),
+                want
+            );
+        }
+    }
+
+    #[test]
+    fn build_same_parsed_url_query_string_and_pairs_as_url() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.with_base_url("https://github.mycorp.com:8443")
+            .unwrap();
+        have.path("a b/issues/new");
+        have.title("Null (the billion dollar mistake) ~ 'quoted'!");
+        have.body(SAMPLE_ISSUE_BODY);
+        have.with_footer("_Filed by MyApp_");
+        have.truncate_body_to_fit(300);
+
+        for profile in [EncodingProfile::Strict, EncodingProfile::GitHubCompatible] {
+            for spaces in [SpaceEncoding::Plus, SpaceEncoding::Percent] {
+                have.encoding_profile(profile);
+                have.space_encoding(spaces);
+
+                let url = have.url().unwrap();
+                let query = have.to_query_string().unwrap();
+                let pairs: Vec<String> = have
+                    .encoded_pairs()
+                    .unwrap()
+                    .map(|(key, value)| format!("{}={}", key, value))
+                    .collect();
+
+                assert!(url.len() <= 300);
+                assert!(query.contains("truncated"));
+                assert_eq!(have.url_parsed().unwrap().as_str(), url);
+                assert_eq!(format!("{}?{}", have.new_issue_path(), query), url);
+                assert_eq!(pairs.join("&"), query);
+            }
+        }
+    }
+
+    #[cfg(feature = "experimental")]
+    #[test]
+    fn build_mobile_deep_link() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Crash");
+
+        assert_eq!(
+            have.mobile_url().unwrap(),
+            "github://EstebanBorai/github-issue-url/issues/new?title=Crash"
+        );
+        assert_eq!(
+            IssueOwned::from(&have).mobile_url().unwrap(),
+            have.mobile_url().unwrap()
+        );
+
+        have.with_base_url("https://github.mycorp.com").unwrap();
+
+        assert_eq!(have.mobile_url().unwrap(), have.url().unwrap());
+    }
+
+    #[test]
+    fn try_url_same_as_url() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.with_base_url("https://github.mycorp.com").unwrap();
+
+        assert!(matches!(have.try_url().unwrap(), Cow::Borrowed(_)));
+        assert_eq!(have.try_url().unwrap(), have.url().unwrap());
+
+        have.reset_to("github-issue-url-cli", "EstebanBorai")
+            .unwrap();
+        have.title("Null: The Billion Dollar Mistake");
+        have.disable_param("title");
+
+        let owned = IssueOwned::from(&have);
+
+        assert!(matches!(have.try_url().unwrap(), Cow::Borrowed(_)));
+        assert!(matches!(owned.try_url().unwrap(), Cow::Borrowed(_)));
+        assert_eq!(have.try_url().unwrap(), have.url().unwrap());
+        assert_eq!(owned.try_url().unwrap(), have.url().unwrap());
+
+        have.with_footer("Sent from MyApp");
+
+        assert!(matches!(have.try_url().unwrap(), Cow::Owned(_)));
+        assert_eq!(have.try_url().unwrap(), have.url().unwrap());
+
+        have.use_template_chooser();
+
+        assert!(matches!(have.try_url().unwrap(), Cow::Owned(_)));
+        assert_eq!(have.try_url().unwrap(), have.url().unwrap());
+    }
+
+    #[test]
+    fn reference_issues_at_body_start() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.reference_issue(Reference::Closes, 1);
+
+        assert_eq!(have.param_value("body"), Some("Closes #1"));
+
+        have.append_body("\nNull is a flag.");
+        have.reference_issue(Reference::Resolves, 2);
+        have.reference_issue(Reference::Closes, 1);
+
+        assert_eq!(
+            have.param_value("body"),
+            Some("Closes #1\nResolves #2\n\nNull is a flag.")
+        );
+        assert_eq!(have.param_count(), 1);
+    }
+
+    #[test]
+    fn keep_a_single_title_param() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("a");
+        have.title("b");
+
+        assert_eq!(have.params(), &[("title", Cow::from("b"))]);
+    }
+
+    #[test]
+    fn replace_assignee_and_template_but_append_assignees() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.assignee("octocat");
+        have.template("bugs.md");
+        have.assignees(&["EstebanBorai"]);
+        have.assignee("EstebanBorai");
+        have.template("features.md");
+        have.assignees(&["octocat"]);
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?assignee=EstebanBorai&template=features.md&assignees=EstebanBorai&assignees=octocat"
+        );
+    }
+
+    #[test]
+    fn append_to_body() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.append_body("Null is a flag.");
+        have.title("Null: The Billion Dollar Mistake");
+        have.append_body("It represents different situations.");
+
+        assert_eq!(
+            have.params(),
+            &[
+                (
+                    "body",
+                    Cow::from("Null is a flag.\nIt represents different situations.")
+                ),
+                ("title", Cow::from("Null: The Billion Dollar Mistake")),
+            ]
+        );
+    }
+
+    #[test]
+    fn append_facts_to_body() {
+        let facts = [("App version", "1.2.3"), ("OS", "macOS\n14")];
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+        let mut owned = IssueOwned::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.body_from_facts(&[]);
+        have.body_from_facts(&facts);
+        owned.body_from_facts(&facts);
+
+        assert_eq!(
+            have.param_value("body"),
+            Some("- App version: 1.2.3\n- OS: macOS 14")
+        );
+        assert_eq!(owned.param_value("body"), have.param_value("body"));
+
+        have.body_from_facts(&facts[..1]);
+        owned.body_from_facts(&facts[..1]);
+
+        assert_eq!(
+            have.param_value("body"),
+            Some("- App version: 1.2.3\n- OS: macOS 14\n\n- App version: 1.2.3")
+        );
+        assert_eq!(owned.param_value("body"), have.param_value("body"));
+    }
+
+    #[test]
+    fn display_issue_url() {
+        let mut have = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("Null: The Billion Dollar Mistake");
+
+        assert_eq!(have.to_string(), have.url().unwrap());
+    }
+
+    #[test]
+    fn parse_issue_url() {
+        let have = Issue::from_url(GITHUB_ISSUE_LINK).unwrap();
+
+        assert_eq!(have.url().unwrap(), GITHUB_ISSUE_LINK.to_string());
+    }
+
+    #[test]
+    fn parse_issue_url_from_parsed_url() {
+        let url = Url::parse(GITHUB_ISSUE_LINK).unwrap();
+        let have = Issue::from_parsed_url(&url).unwrap();
+
+        assert_eq!(have, Issue::from_url(GITHUB_ISSUE_LINK).unwrap());
+        assert!(matches!(
+            Issue::from_parsed_url(&Url::parse("https://github.com/EstebanBorai").unwrap()),
+            Err(Error::NotAnIssueUrl)
+        ));
+    }
+
+    #[test]
+    fn parse_issue_url_keeps_repeated_params() {
+        let url = "https://github.com/EstebanBorai/github-issue-url/issues/new?assignees=EstebanBorai&title=Hello&assignees=octocat";
+        let have = Issue::from_url(url).unwrap();
+
+        assert_eq!(
+            have.params()
+                .iter()
+                .filter(|(key, _)| key == "assignees")
+                .map(|(_, value)| value.as_str())
+                .collect::<Vec<_>>(),
+            vec!["EstebanBorai", "octocat"]
+        );
+        assert_eq!(have.url().unwrap(), url);
+    }
+
+    #[test]
+    fn parse_issue_url_keeps_base_url() {
+        let have = Issue::from_url(
+            "https://github.mycorp.com/EstebanBorai/github-issue-url/issues/new?title=Hello+World",
+        )
+        .unwrap();
+
+        let mut want = IssueOwned::new("github-issue-url", "EstebanBorai").unwrap();
+
+        want.with_base_url("https://github.mycorp.com").unwrap();
+        want.title("Hello World");
+
+        assert_eq!(have, want);
+    }
+
+    #[test]
+    fn return_error_if_url_is_not_an_issue_url() {
+        assert!(matches!(
+            Issue::from_url("not a url"),
+            Err(Error::UrlParseError(
+                url::ParseError::RelativeUrlWithoutBase
+            ))
+        ));
+        assert_eq!(
+            Issue::from_url("not a url").unwrap_err().to_string(),
+            "Failed to parse URL with provided params. relative URL without a base"
+        );
+        assert!(matches!(
+            Issue::from_url("https://github.com/EstebanBorai/github-issue-url/pulls"),
+            Err(Error::NotAnIssueUrl)
+        ));
+        assert!(matches!(
+            Issue::from_url("https://github.com/EstebanBorai/issues/new"),
+            Err(Error::NotAnIssueUrl)
+        ));
+
+        for url in [
+            "https://github.com//EstebanBorai//github-issue-url/issues/new",
+            "https://github.com//EstebanBorai/github-issue-url/issues/new",
+            "https://github.com/EstebanBorai/github-issue-url//issues/new",
+            "https://github.com/EstebanBorai/github-issue-url/issues/new//",
+        ] {
+            assert!(matches!(Issue::from_url(url), Err(Error::NotAnIssueUrl)));
+        }
+
+        assert_eq!(
+            Issue::from_url("https://github.com/EstebanBorai/github-issue-url/issues/new/?title=a")
+                .unwrap()
+                .url()
+                .unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=a"
+        );
+    }
+
+    #[test]
+    fn build_issue_from_slug() {
+        let have = Issue::from_slug("EstebanBorai/github-issue-url").unwrap();
+
+        assert_eq!(
+            have,
+            Issue::new("github-issue-url", "EstebanBorai").unwrap()
+        );
+    }
+
+    #[test]
+    fn return_error_if_slug_is_invalid() {
+        assert!(matches!(
+            Issue::from_slug("github-issue-url"),
+            Err(Error::InvalidSlug(_))
+        ));
+        assert!(matches!(
+            Issue::from_slug("EstebanBorai/github-issue-url/issues"),
+            Err(Error::InvalidSlug(_))
+        ));
+        assert!(matches!(
+            Issue::from_slug("EstebanBorai/"),
+            Err(Error::EmptyRepositoryName)
+        ));
+    }
+
+    #[test]
+    fn return_error_if_repository_owner_breaks_naming_rules() {
+        for owner in &[
+            "Esteban Borai",
+            "Esteban/Borai",
+            "-EstebanBorai",
+            "EstebanBorai-",
+            "Esteban--Borai",
+            "EstebanBoraiEstebanBoraiEstebanBoraiEstebanBorai",
+        ] {
+            assert!(matches!(
+                Issue::new("github-issue-url", owner),
+                Err(Error::InvalidRepositoryOwner(have)) if have == *owner
+            ));
+        }
+    }
+
+    #[test]
+    fn return_error_if_repository_name_breaks_naming_rules() {
+        for repository in &["github issue url", "github/issue-url", "..", "¿url?"] {
+            assert!(matches!(
+                Issue::new(repository, "EstebanBorai"),
+                Err(Error::InvalidRepositoryName(have)) if have == *repository
+            ));
+        }
+
+        assert!(Issue::new("github_issue.url", "Esteban-Borai").is_ok());
+    }
+
+    #[test]
+    fn build_unchecked_issue() {
+        let mut have = Issue::new_unchecked("github-issue-url", "Esteban/Borai");
+
+        have.title("Hello");
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/Esteban%2FBorai/github-issue-url/issues/new?title=Hello"
+        );
     }
 
     #[test]
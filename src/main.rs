@@ -0,0 +1,185 @@
+use std::io::{self, Read};
+use std::path::Path;
+use std::process;
+
+use github_issue_url::error::{Error, Result};
+use github_issue_url::IssueOwned;
+
+const USAGE: &str = "\
+Prints a GitHub prefilled issue URL
+
+Usage: github-issue-url --repo <REPO> --owner <OWNER> [OPTIONS]
+
+Options:
+    --repo <REPO>           Repository name
+    --owner <OWNER>         Repository owner, user or organization
+    --title <TITLE>         Issue title
+    --body <BODY>           Issue body
+    --body-file <PATH>      Reads the issue body from a file, or stdin if `-`
+    --label <LABEL>         Issue label, can be repeated
+    --assignee <ASSIGNEE>   Username of the issue assignee
+    --template <TEMPLATE>   Issue template file name
+    --base-url <URL>        Host other than https://github.com
+    -h, --help              Prints this message
+";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Args {
+    repo: String,
+    owner: String,
+    title: Option<String>,
+    body: Option<String>,
+    body_file: Option<String>,
+    labels: Vec<String>,
+    assignee: Option<String>,
+    template: Option<String>,
+    base_url: Option<String>,
+}
+
+/// Parses the command line arguments, returning `None` if help is requested
+fn parse_args(args: impl IntoIterator<Item = String>) -> std::result::Result<Option<Args>, String> {
+    let mut parsed = Args::default();
+    let mut repo = None;
+    let mut owner = None;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if arg == "-h" || arg == "--help" {
+            return Ok(None);
+        }
+
+        let value = match args.next() {
+            Some(value) => value,
+            None => return Err(format!("missing value for `{}`", arg)),
+        };
+
+        match arg.as_str() {
+            "--repo" => repo = Some(value),
+            "--owner" => owner = Some(value),
+            "--title" => parsed.title = Some(value),
+            "--body" => parsed.body = Some(value),
+            "--body-file" => parsed.body_file = Some(value),
+            "--label" => parsed.labels.push(value),
+            "--assignee" => parsed.assignee = Some(value),
+            "--template" => parsed.template = Some(value),
+            "--base-url" => parsed.base_url = Some(value),
+            _ => return Err(format!("unknown argument `{}`", arg)),
+        }
+    }
+
+    if parsed.body.is_some() && parsed.body_file.is_some() {
+        return Err(String::from(
+            "`--body` and `--body-file` can't be used together",
+        ));
+    }
+
+    parsed.repo = repo.ok_or("missing required argument `--repo`")?;
+    parsed.owner = owner.ok_or("missing required argument `--owner`")?;
+
+    Ok(Some(parsed))
+}
+
+fn issue(args: Args) -> Result<IssueOwned> {
+    let mut issue = IssueOwned::new(args.repo, args.owner)?;
+
+    if let Some(base_url) = args.base_url {
+        issue.with_base_url(&base_url)?;
+    }
+
+    if let Some(title) = args.title {
+        issue.title(title);
+    }
+
+    if let Some(body) = args.body {
+        issue.body(body);
+    }
+
+    match args.body_file.as_deref() {
+        Some("-") => {
+            let mut body = String::new();
+
+            io::stdin()
+                .read_to_string(&mut body)
+                .map_err(|e| Error::Io(e.to_string()))?;
+            issue.body(body);
+        }
+        Some(path) => issue.body_from_file(Path::new(path))?,
+        None => {}
+    }
+
+    if !args.labels.is_empty() {
+        issue.labels_slice(&args.labels)?;
+    }
+
+    if let Some(assignee) = args.assignee {
+        issue.assignee(assignee);
+    }
+
+    if let Some(template) = args.template {
+        issue.template(template);
+    }
+
+    Ok(issue)
+}
+
+fn main() {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            print!("{}", USAGE);
+            return;
+        }
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+
+    match issue(args).and_then(|issue| issue.url()) {
+        Ok(url) => println!("{}", url),
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> std::result::Result<Option<Args>, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn build_issue_url_from_args() {
+        let have = args(&[
+            "--repo",
+            "github-issue-url",
+            "--owner",
+            "EstebanBorai",
+            "--title",
+            "Null: The Billion Dollar Mistake",
+            "--label",
+            "bug",
+            "--label",
+            "production",
+        ])
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            issue(have).unwrap().url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake&labels=bug%2Cproduction"
+        );
+    }
+
+    #[test]
+    fn return_error_on_invalid_args() {
+        assert_eq!(args(&["--help"]), Ok(None));
+        assert!(args(&["--owner", "EstebanBorai"]).is_err());
+        assert!(args(&["--repo", "github-issue-url", "--owner"]).is_err());
+        assert!(args(&["--repo", "github-issue-url", "--owner", "a", "--foo", "b"]).is_err());
+    }
+}
@@ -0,0 +1,296 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::error::{Error, Result};
+
+/// Wraps `content` in a fenced code block tagged with `lang`.
+///
+/// The fence is made longer than the longest run of backticks in `content`,
+/// so content including its own code blocks doesn't break out of the fence.
+pub(crate) fn code_block(lang: &str, content: &str) -> String {
+    let longest_run = content.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run.max(2) + 1);
+    let newline = if content.ends_with('\n') { "" } else { "\n" };
+
+    format!("{}{}\n{}{}{}", fence, lang, content, newline, fence)
+}
+
+/// Wraps `content` in a collapsible `<details>` block with `summary` as the
+/// toggle label.
+///
+/// `summary` is HTML escaped so tags such as `</summary>` in a panic message
+/// don't break the block. Blank lines are kept around `content`, otherwise
+/// GitHub doesn't render markdown inside the block.
+pub(crate) fn details_block(summary: &str, content: &str) -> String {
+    format!(
+        "<details>\n<summary>{}</summary>\n\n{}\n\n</details>",
+        escape_html(summary),
+        content.trim_end_matches('\n')
+    )
+}
+
+/// Describes `error` and its chain of sources in a code block, listing each
+/// source under `Caused by:`.
+#[cfg(feature = "std")]
+pub(crate) fn error_chain(error: &dyn std::error::Error) -> String {
+    let mut chain = error.to_string();
+    let mut source = error.source();
+
+    if source.is_some() {
+        chain.push_str("\n\nCaused by:");
+    }
+
+    let mut index = 0;
+
+    while let Some(error) = source {
+        chain.push_str(&format!("\n    {}: {}", index, error));
+        source = error.source();
+        index += 1;
+    }
+
+    code_block("", &chain)
+}
+
+/// Builds a markdown link to `url`, escaping `text` so brackets and
+/// parentheses in it don't end the link early, and escaping parentheses in
+/// `url`.
+pub(crate) fn link(text: &str, url: &str) -> String {
+    format!(
+        "[{}]({})",
+        escape(text, &['\\', '[', ']', '(', ')']),
+        escape(url, &['(', ')'])
+    )
+}
+
+/// Builds a markdown image with `alt` as the alternative text. Refer to
+/// `link`.
+pub(crate) fn image(alt: &str, url: &str) -> String {
+    format!("!{}", link(alt, url))
+}
+
+/// Builds a markdown list with one `- name: value` item for each of `facts`,
+/// newlines in values are replaced by spaces so each fact stays in one item.
+pub(crate) fn facts_list(facts: &[(&str, &str)]) -> String {
+    let items: Vec<String> = facts
+        .iter()
+        .map(|(name, value)| format!("- {}: {}", name, value.replace(['\r', '\n'], " ")))
+        .collect();
+
+    items.join("\n")
+}
+
+/// Builds a GitHub-flavored markdown table with a `headers` row, the
+/// separator row and one row for each of `rows`.
+///
+/// Pipes (`|`) in cells are escaped and newlines are replaced by `<br>`, so
+/// cells don't break the table. Returns `Error::TableShapeMismatch` if any
+/// row doesn't have one cell per header.
+pub(crate) fn table(headers: &[&str], rows: &[Vec<String>]) -> Result<String> {
+    if let Some((row, cells)) = rows
+        .iter()
+        .enumerate()
+        .find(|(_, cells)| cells.len() != headers.len())
+    {
+        return Err(Error::TableShapeMismatch {
+            row,
+            columns: cells.len(),
+            expected: headers.len(),
+        });
+    }
+
+    let separator: Vec<&str> = headers.iter().map(|_| "---").collect();
+    let mut table = table_row(headers);
+
+    table.push('\n');
+    table.push_str(&table_row(&separator));
+
+    for cells in rows {
+        table.push('\n');
+        table.push_str(&table_row(cells));
+    }
+
+    Ok(table)
+}
+
+/// Builds a table row, escaping each of `cells`
+fn table_row<S: AsRef<str>>(cells: &[S]) -> String {
+    let cells: Vec<String> = cells
+        .iter()
+        .map(|cell| {
+            escape(cell.as_ref(), &['|'])
+                .replace("\r\n", "<br>")
+                .replace('\n', "<br>")
+        })
+        .collect();
+
+    format!("| {} |", cells.join(" | "))
+}
+
+/// Escapes each of `chars` in `text` with a backslash
+fn escape(text: &str, chars: &[char]) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if chars.contains(&c) {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// Builds an HTML `<a>` tag to `url`, escaping both `text` and `url`.
+///
+/// When `target_blank` is set, the link opens in a new tab with
+/// `rel="noopener noreferrer"`.
+pub(crate) fn html_anchor(text: &str, url: &str, target_blank: bool) -> String {
+    let target = if target_blank {
+        " target=\"_blank\" rel=\"noopener noreferrer\""
+    } else {
+        ""
+    };
+
+    format!(
+        "<a href=\"{}\"{}>{}</a>",
+        escape_html(url),
+        target,
+        escape_html(text)
+    )
+}
+
+/// Escapes characters with special meaning in HTML text and attributes
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_content_in_code_block() {
+        assert_eq!(
+            code_block("rust", "fn main() {}"),
+            "```rust\nfn main() {}\n```"
+        );
+        assert_eq!(code_block("", "panicked\n"), "```\npanicked\n```");
+    }
+
+    #[test]
+    fn uses_longer_fence_if_content_has_fences() {
+        assert_eq!(
+            code_block("md", "```rust\nfn main() {}\n```"),
+            "````md\n```rust\nfn main() {}\n```\n````"
+        );
+        assert_eq!(code_block("", "`````"), "``````\n`````\n``````");
+    }
+
+    #[test]
+    fn wraps_content_in_details_block() {
+        assert_eq!(
+            details_block("Logs", "```\npanicked\n```\n"),
+            "<details>\n<summary>Logs</summary>\n\n```\npanicked\n```\n\n</details>"
+        );
+    }
+
+    #[test]
+    fn escapes_details_block_summary() {
+        assert_eq!(
+            details_block("panicked at '<unnamed>' </summary>", "```\npanicked\n```"),
+            "<details>\n<summary>panicked at &#39;&lt;unnamed&gt;&#39; &lt;/summary&gt;</summary>\n\n```\npanicked\n```\n\n</details>"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn describes_error_chain() {
+        #[derive(Debug)]
+        struct RenderError(std::fmt::Error);
+
+        impl std::fmt::Display for RenderError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "Failed to render <report> & [summary]")
+            }
+        }
+
+        impl std::error::Error for RenderError {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        assert_eq!(
+            error_chain(&RenderError(std::fmt::Error)),
+            "```\nFailed to render <report> & [summary]\n\nCaused by:\n    0: an error occurred when formatting an argument\n```"
+        );
+        assert_eq!(
+            error_chain(&std::fmt::Error),
+            "```\nan error occurred when formatting an argument\n```"
+        );
+    }
+
+    #[test]
+    fn builds_escaped_markdown_table() {
+        let rows = vec![
+            vec!["os".to_string(), "linux".to_string()],
+            vec!["cmd".to_string(), "a | b\nc".to_string()],
+        ];
+
+        assert_eq!(
+            table(&["Name", "Value"], &rows).unwrap(),
+            "| Name | Value |\n| --- | --- |\n| os | linux |\n| cmd | a \\| b<br>c |"
+        );
+        assert!(matches!(
+            table(&["Name"], &rows),
+            Err(Error::TableShapeMismatch {
+                row: 0,
+                columns: 2,
+                expected: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn builds_escaped_markdown_link() {
+        assert_eq!(
+            link("Report [bug] (now)", "https://github.com"),
+            "[Report \\[bug\\] \\(now\\)](https://github.com)"
+        );
+    }
+
+    #[test]
+    fn builds_escaped_markdown_image() {
+        assert_eq!(
+            image("Screenshot [1]", "https://example.com/a_(b).png"),
+            "![Screenshot \\[1\\]](https://example.com/a_\\(b\\).png)"
+        );
+    }
+
+    #[test]
+    fn builds_escaped_html_anchor() {
+        assert_eq!(
+            html_anchor("Bugs & <Fixes>", "https://github.com/?a=1&b=2", false),
+            "<a href=\"https://github.com/?a=1&amp;b=2\">Bugs &amp; &lt;Fixes&gt;</a>"
+        );
+        assert_eq!(
+            html_anchor("Report", "https://github.com", true),
+            "<a href=\"https://github.com\" target=\"_blank\" rel=\"noopener noreferrer\">Report</a>"
+        );
+    }
+}
@@ -0,0 +1,782 @@
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use url::Url;
+
+use crate::error::{Error, Result};
+#[cfg(feature = "std")]
+use crate::template::expand_vars;
+use crate::{
+    join_ids, join_labels, referenced_body, truncated_title, EncodingProfile, Issue, Options,
+    Reference, RepoRef, SpaceEncoding, Template,
+};
+
+/// Owned counterpart of `Issue`.
+///
+/// Holds `String`s instead of borrowed slices, which is handy when issue
+/// fields are built at runtime (e.g. a formatted panic message) and don't
+/// outlive the issue itself.
+///
+/// The URL is built by borrowing an `Issue` out of this struct, so both types
+/// always produce the same link.
+///
+/// With the `serde` feature enabled, `IssueOwned` implements `Serialize` and
+/// `Deserialize`, whereas `Issue` only implements `Serialize`.
+///
+/// Both types share the same format: `repository_name`, `repository_owner`,
+/// `params` as `[key, value]` pairs and the options set through setters as
+/// top-level fields, which are `base_url`, `url_length_limit`,
+/// `truncate_body_to_fit`, `sort_params`, `space_encoding`,
+/// `encoding_profile`, `normalize_newlines`, `template_chooser`,
+/// `has_write_access`, `derive_title`, `disabled_params`, `strip_ansi`,
+/// `footer` and `path`. Options left out take their default value when
+/// deserializing. The repository is validated same as with `IssueOwned::new`
+/// and the base URL same as with `IssueOwned::with_base_url`, so invalid
+/// input fails to deserialize.
+///
+/// # Example
+///
+/// ```
+/// use github_issue_url::IssueOwned;
+///
+/// let mut have = IssueOwned::new("github-issue-url", "EstebanBorai").unwrap();
+///
+/// have.title(format!("Panicked at {}", "src/main.rs:1:1"));
+///
+/// let have = have.url().unwrap();
+///
+/// assert_eq!(have, "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Panicked+at+src%2Fmain.rs%3A1%3A1");
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IssueOwned {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    repo: RepoRef<'static>,
+    params: Vec<(String, String)>,
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    options: Options,
+}
+
+impl IssueOwned {
+    pub fn new(
+        repository_name: impl Into<String>,
+        repository_owner: impl Into<String>,
+    ) -> Result<Self> {
+        let repo = RepoRef::new(repository_owner.into(), repository_name.into())?;
+
+        Ok(IssueOwned {
+            repo,
+            params: Vec::new(),
+            options: Options::default(),
+        })
+    }
+
+    /// The username of the issue's assignee, replacing any assignee previously
+    /// set. Refer to `Issue::assignee`.
+    pub fn assignee(&mut self, assignee: impl Into<String>) {
+        self.set_param("assignee", assignee);
+    }
+
+    /// Assigns the issue to whoever opens it. Refer to
+    /// `Issue::assign_to_self`.
+    pub fn assign_to_self(&mut self) {
+        self.set_param("assignee", "@me");
+    }
+
+    /// The usernames of the issue's assignees. Refer to `Issue::assignees`.
+    pub fn assignees<S: AsRef<str>>(&mut self, assignees: &[S]) {
+        for assignee in assignees {
+            self.push_param("assignees", assignee.as_ref());
+        }
+    }
+
+    /// Human-readable summary of the prefilled fields. Refer to
+    /// `Issue::prefilled_fields`.
+    pub fn prefilled_fields(&self) -> Vec<(String, String)> {
+        self.as_issue().prefilled_fields()
+    }
+
+    /// Params currently set, in the same order they were set.
+    pub fn params(&self) -> &[(String, String)] {
+        &self.params
+    }
+
+    /// Number of params currently set. Refer to `Issue::param_count`.
+    pub fn param_count(&self) -> usize {
+        self.params.len()
+    }
+
+    /// Whether any param is set. Refer to `Issue::has_params`.
+    pub fn has_params(&self) -> bool {
+        !self.params.is_empty()
+    }
+
+    /// Compares two issues ignoring the order in which params were set. Refer
+    /// to `Issue::semantically_eq`.
+    pub fn semantically_eq(&self, other: &IssueOwned) -> bool {
+        self.as_issue().semantically_eq(&other.as_issue())
+    }
+
+    /// Canonical string describing the repository and the params set. Refer
+    /// to `Issue::cache_key`.
+    pub fn cache_key(&self) -> String {
+        self.as_issue().cache_key()
+    }
+
+    /// Whether a param with the provided `key` is set. Refer to
+    /// `Issue::contains_param`.
+    pub fn contains_param(&self, key: &str) -> bool {
+        self.params.iter().any(|(param, _)| param == key)
+    }
+
+    /// Value of the first param with the provided `key`. Refer to
+    /// `Issue::param_value`.
+    pub fn param_value(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Removes every param. Refer to `Issue::clear_params`.
+    pub fn clear_params(&mut self) {
+        self.params.clear();
+    }
+
+    /// Points the issue to a different repository and removes every param.
+    /// Refer to `Issue::reset_to`.
+    pub fn reset_to(&mut self, repository_name: &str, repository_owner: &str) -> Result<()> {
+        self.repo
+            .reset_to(repository_owner.to_string(), repository_name.to_string())?;
+        self.params.clear();
+
+        Ok(())
+    }
+
+    /// Removes every param with the provided `key`
+    pub fn remove_param(&mut self, key: &str) {
+        self.params.retain(|(param, _)| param != key);
+    }
+
+    /// Leaves params with the provided `key` out of the URL, keeping their
+    /// values. Refer to `Issue::disable_param`.
+    pub fn disable_param(&mut self, key: &str) {
+        self.options.disable_param(key);
+    }
+
+    /// Includes params with the provided `key` in the URL again. Refer to
+    /// `Issue::enable_param`.
+    pub fn enable_param(&mut self, key: &str) {
+        self.options.enable_param(key);
+    }
+
+    /// Whether params with the provided `key` are included in the URL. Refer
+    /// to `Issue::is_param_enabled`.
+    pub fn is_param_enabled(&self, key: &str) -> bool {
+        self.options.is_param_enabled(key)
+    }
+
+    /// Prefilled issue body content, replacing any body previously set. Refer
+    /// to `Issue::body`.
+    pub fn body(&mut self, body: impl Into<String>) {
+        let body = body.into();
+
+        if body.is_empty() {
+            return self.remove_param("body");
+        }
+
+        self.set_param("body", body);
+    }
+
+    /// Reads the file at `path` and uses its content as the body. Refer to
+    /// `Issue::body_from_file`.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn body_from_file(&mut self, path: &std::path::Path) -> Result<()> {
+        let body = std::fs::read_to_string(path).map_err(|e| Error::Io(e.to_string()))?;
+
+        self.body(body);
+
+        Ok(())
+    }
+
+    /// Uses `template` as the body with environment variables expanded.
+    /// Refer to `Issue::body_from_env_template`.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn body_from_env_template(&mut self, template: &str) -> Result<()> {
+        let body = expand_vars(template, |name| std::env::var(name).ok())?;
+
+        self.body(body);
+
+        Ok(())
+    }
+
+    /// Uses `error` and its chain of sources as the body. Refer to
+    /// `Issue::body_from_error`.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn body_from_error(&mut self, error: &dyn std::error::Error) {
+        self.set_param("body", crate::markdown::error_chain(error));
+    }
+
+    /// Appends content to the body. Refer to `Issue::append_body`.
+    pub fn append_body(&mut self, body: impl Into<String>) {
+        let body = body.into();
+
+        match self.params.iter_mut().find(|(key, _)| key == "body") {
+            Some((_, current)) => {
+                current.push('\n');
+                current.push_str(&body);
+            }
+            None => self.body(body),
+        }
+    }
+
+    /// Renders `facts` as a markdown list and appends it to the body. Refer
+    /// to `Issue::body_from_facts`.
+    pub fn body_from_facts(&mut self, facts: &[(&str, &str)]) {
+        if facts.is_empty() {
+            return;
+        }
+
+        let facts = crate::markdown::facts_list(facts);
+
+        match self.params.iter_mut().find(|(key, _)| key == "body") {
+            Some((_, current)) => {
+                current.push_str("\n\n");
+                current.push_str(&facts);
+            }
+            None => self.set_param("body", facts),
+        }
+    }
+
+    /// Footer appended to the body when building the URL. Refer to
+    /// `Issue::with_footer`.
+    pub fn with_footer(&mut self, footer: impl Into<String>) {
+        self.options.footer = Some(footer.into());
+    }
+
+    /// References another issue at the start of the body. Refer to
+    /// `Issue::reference_issue`.
+    pub fn reference_issue(&mut self, keyword: Reference, number: u64) {
+        let current = self.param_value("body").unwrap_or_default();
+
+        if let Some(body) = referenced_body(current, keyword, number) {
+            self.set_param("body", body);
+        }
+    }
+
+    /// Arbitrary query param. Refer to `Issue::custom_param`.
+    pub fn custom_param(&mut self, key: impl Into<String>, value: impl Into<String>) -> Result<()> {
+        self.push_checked_param(key, value)
+    }
+
+    /// Pushes a new param, keeping any param previously set with the same
+    /// key. Refer to `Issue::append_param`.
+    pub fn append_param(&mut self, key: impl Into<String>, value: impl Into<String>) -> Result<()> {
+        self.push_checked_param(key, value)
+    }
+
+    /// Prefills the fields of a YAML issue form. Refer to
+    /// `Issue::form_fields`.
+    pub fn form_fields<K: AsRef<str>, V: AsRef<str>>(
+        &mut self,
+        template: impl Into<String>,
+        fields: &[(K, V)],
+    ) -> Result<()> {
+        if fields.iter().any(|(id, _)| id.as_ref().is_empty()) {
+            return Err(Error::EmptyParamKey);
+        }
+
+        self.template(template);
+
+        for (id, value) in fields {
+            self.push_param(id.as_ref(), value.as_ref());
+        }
+
+        Ok(())
+    }
+
+    /// Issue labels separated by comma (`,`). Refer to `Issue::labels`.
+    pub fn labels(&mut self, labels: impl Into<String>) {
+        self.push_param("labels", labels);
+    }
+
+    /// Issue labels provided as a slice. Refer to `Issue::labels_slice`.
+    pub fn labels_slice<S: AsRef<str>>(&mut self, labels: &[S]) -> Result<()> {
+        self.push_param("labels", join_labels(labels)?);
+
+        Ok(())
+    }
+
+    /// The ID (number) of the milestone. Refer to `Issue::milestone`.
+    pub fn milestone(&mut self, milestone: impl Into<String>) {
+        self.set_param("milestone", milestone);
+    }
+
+    /// The ID (number) of the milestone. Refer to `Issue::milestone_id`.
+    pub fn milestone_id(&mut self, milestone: u64) {
+        self.set_param("milestone", milestone.to_string());
+    }
+
+    /// The IDs (number) of the projects. Refer to `Issue::projects`.
+    pub fn projects(&mut self, projects: impl Into<String>) {
+        self.push_param("projects", projects);
+    }
+
+    /// The IDs (number) of the projects provided as a slice. Refer to
+    /// `Issue::projects_slice`.
+    pub fn projects_slice(&mut self, projects: &[u64]) {
+        self.push_param("projects", join_ids(projects));
+    }
+
+    /// Adds the issue to a project of the current Projects experience. Refer
+    /// to `Issue::project_v2`.
+    pub fn project_v2(&mut self, owner: &str, number: u64) {
+        self.push_param("projects", format!("{}/{}", owner, number));
+    }
+
+    /// Prefilled issue title, replacing any title previously set. Refer to
+    /// `Issue::title`.
+    pub fn title(&mut self, title: impl Into<String>) {
+        let title = title.into();
+
+        if title.is_empty() {
+            return self.remove_param("title");
+        }
+
+        self.set_param("title", title);
+    }
+
+    /// Prefilled issue title, shortened to at most `max` chars. Refer to
+    /// `Issue::title_truncated`.
+    pub fn title_truncated(&mut self, title: impl AsRef<str>, max: usize) {
+        self.title(truncated_title(title.as_ref(), max));
+    }
+
+    /// The name of the issue template, replacing any template previously set.
+    /// Refer to `Issue::template`.
+    pub fn template(&mut self, template: impl Into<String>) {
+        self.set_param("template", template);
+    }
+
+    /// The issue template to use. Refer to `Issue::template_kind`.
+    pub fn template_kind(&mut self, template: Template<'_>) {
+        self.template(template.file_name());
+    }
+
+    /// Checks that the template exists in the local checkout at `repo_root`.
+    /// Refer to `Issue::validate_template_exists`.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn validate_template_exists(&self, repo_root: &std::path::Path) -> Result<()> {
+        self.as_issue().validate_template_exists(repo_root)
+    }
+
+    /// Host to build the issue URL for. Refer to `Issue::with_base_url`.
+    pub fn with_base_url(&mut self, base_url: &str) -> Result<()> {
+        self.repo.with_base_url(base_url)
+    }
+
+    /// Maximum length in bytes of the URL returned by
+    /// `IssueOwned::url_checked`. Refer to `Issue::url_length_limit`.
+    pub fn url_length_limit(&mut self, limit: usize) {
+        self.options.url_length_limit = limit;
+    }
+
+    /// Shortens the body in the generated URL to fit within `max_url_len`.
+    /// Refer to `Issue::truncate_body_to_fit`.
+    pub fn truncate_body_to_fit(&mut self, max_url_len: usize) {
+        self.options.truncate_body_to_fit = Some(max_url_len);
+    }
+
+    /// Emits query params in a fixed order. Refer to `Issue::sort_params`.
+    pub fn sort_params(&mut self) {
+        self.options.sort_params = true;
+    }
+
+    /// Uses the first non-empty line of the body as title if no title is set.
+    /// Refer to `Issue::derive_title_from_body`.
+    pub fn derive_title_from_body(&mut self) {
+        self.options.derive_title = true;
+    }
+
+    /// Whether the issue author has write access to the repository. Refer to
+    /// `Issue::has_write_access`.
+    pub fn has_write_access(&mut self, has_write_access: bool) {
+        self.options.has_write_access = has_write_access;
+    }
+
+    /// Removes ANSI escape sequences from the body when building the URL.
+    /// Refer to `Issue::strip_ansi`.
+    pub fn strip_ansi(&mut self) {
+        self.options.strip_ansi = true;
+    }
+
+    /// Targets the template chooser instead of the "New Issue" form. Refer to
+    /// `Issue::use_template_chooser`.
+    pub fn use_template_chooser(&mut self) {
+        self.options.template_chooser = true;
+    }
+
+    /// Path within the repository to append the query params to, instead of
+    /// `issues/new`. Refer to `Issue::path`.
+    pub fn path(&mut self, path: impl Into<String>) {
+        let path = path.into();
+
+        self.options.path = Some(path.trim_matches('/').to_string());
+    }
+
+    /// Whether newlines in the body are converted to `\n` when building the
+    /// URL. Refer to `Issue::normalize_newlines`.
+    pub fn normalize_newlines(&mut self, normalize: bool) {
+        self.options.normalize_newlines = normalize;
+    }
+
+    /// Sets how spaces are encoded in the URL query. Refer to
+    /// `Issue::space_encoding`.
+    pub fn space_encoding(&mut self, space_encoding: SpaceEncoding) {
+        self.options.space_encoding = space_encoding;
+    }
+
+    /// Sets how the URL query is percent-encoded. Refer to
+    /// `Issue::encoding_profile`.
+    pub fn encoding_profile(&mut self, encoding_profile: EncodingProfile) {
+        self.options.encoding_profile = encoding_profile;
+    }
+
+    /// The "New Issue" URL without query params. Refer to
+    /// `Issue::new_issue_path`.
+    pub fn new_issue_path(&self) -> String {
+        self.as_issue().new_issue_path()
+    }
+
+    #[must_use = "building the URL has no effect unless it is used"]
+    pub fn url(&self) -> Result<String> {
+        self.as_issue().url()
+    }
+
+    /// Builds the issue URL, borrowing it when there is no query to append.
+    /// Refer to `Issue::try_url`.
+    #[must_use = "building the URL has no effect unless it is used"]
+    pub fn try_url(&self) -> Result<Cow<'_, str>> {
+        match self.as_issue().try_url()? {
+            Cow::Borrowed(_) => Ok(Cow::Borrowed(self.repo.new_issue_url())),
+            Cow::Owned(url) => Ok(Cow::Owned(url)),
+        }
+    }
+
+    /// Writes the issue URL into `buf`. Refer to `Issue::write_url`.
+    pub fn write_url(&self, buf: &mut String) -> Result<()> {
+        self.as_issue().write_url(buf)
+    }
+
+    /// Builds the issue URL as a `url::Url`. Refer to `Issue::url_parsed`.
+    pub fn url_parsed(&self) -> Result<Url> {
+        self.as_issue().url_parsed()
+    }
+
+    /// The percent-encoded query of the URL, without the leading `?`. Refer to
+    /// `Issue::to_query_string`.
+    pub fn to_query_string(&self) -> Result<String> {
+        self.as_issue().to_query_string()
+    }
+
+    /// Iterates the percent-encoded key/value pairs of the URL query. Refer to
+    /// `Issue::encoded_pairs`.
+    pub fn encoded_pairs(&self) -> Result<impl Iterator<Item = (String, String)>> {
+        self.as_issue().encoded_pairs()
+    }
+
+    /// Iterates the key/value pairs currently set, without percent-encoding.
+    /// Refer to `Issue::query_pairs`.
+    pub fn query_pairs(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.params
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Builds the issue URL along with whether it exceeds the length limit.
+    /// Refer to `Issue::url_with_length_flag`.
+    #[must_use = "building the URL has no effect unless it is used"]
+    pub fn url_with_length_flag(&self) -> Result<(String, bool)> {
+        self.as_issue().url_with_length_flag()
+    }
+
+    /// Builds the issue URL failing if it exceeds the length limit. Refer to
+    /// `Issue::url_checked`.
+    #[must_use = "building the URL has no effect unless it is used"]
+    pub fn url_checked(&self) -> Result<String> {
+        self.as_issue().url_checked()
+    }
+
+    /// Checks the issue for every known problem at once. Refer to
+    /// `Issue::validate`.
+    pub fn validate(&self) -> core::result::Result<(), Vec<Error>> {
+        self.as_issue().validate()
+    }
+
+    /// Builds the issue URL wrapped in a markdown link. Refer to
+    /// `Issue::markdown_link`.
+    pub fn markdown_link(&self, text: &str) -> Result<String> {
+        self.as_issue().markdown_link(text)
+    }
+
+    /// Builds the issue URL wrapped in an HTML `<a>` tag. Refer to
+    /// `Issue::html_anchor`.
+    pub fn html_anchor(&self, text: &str, target_blank: bool) -> Result<String> {
+        self.as_issue().html_anchor(text, target_blank)
+    }
+
+    /// Builds the issue URL and opens it in the default browser. Refer to
+    /// `Issue::open`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use github_issue_url::Issue;
+    ///
+    /// std::panic::set_hook(Box::new(|info| {
+    ///     if let Ok(issue) = Issue::from_panic_info("github-issue-url", "EstebanBorai", info) {
+    ///         let _ = issue.open();
+    ///     }
+    /// }));
+    /// ```
+    ///
+    /// Requires the `open` feature.
+    #[cfg(feature = "open")]
+    pub fn open(&self) -> Result<()> {
+        self.as_issue().open()
+    }
+
+    /// Builds a `github://` deep link for the GitHub mobile app. Refer to
+    /// `Issue::mobile_url`.
+    ///
+    /// Requires the `experimental` feature.
+    #[cfg(feature = "experimental")]
+    #[must_use = "building the URL has no effect unless it is used"]
+    pub fn mobile_url(&self) -> Result<String> {
+        self.as_issue().mobile_url()
+    }
+
+    pub(crate) fn push_param(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.params.push((key.into(), value.into()));
+    }
+
+    /// Pushes a param with a caller provided `key`, failing with
+    /// `Error::EmptyParamKey` if `key` is empty
+    fn push_checked_param(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Result<()> {
+        let key = key.into();
+
+        if key.is_empty() {
+            return Err(Error::EmptyParamKey);
+        }
+
+        self.push_param(key, value);
+
+        Ok(())
+    }
+
+    /// Sets a single-valued param, replacing its current value in place and
+    /// dropping any other param with the same key
+    fn set_param(&mut self, key: &str, value: impl Into<String>) {
+        let value = value.into();
+
+        match self.params.iter().position(|(param, _)| param == key) {
+            Some(index) => {
+                self.params[index].1 = value;
+
+                let mut position = 0;
+
+                self.params.retain(|(param, _)| {
+                    position += 1;
+                    param != key || position == index + 1
+                });
+            }
+            None => self.push_param(key, value),
+        }
+    }
+
+    /// Borrows an `Issue` out of this struct, used to share the URL building
+    /// logic between both types.
+    fn as_issue(&self) -> Issue<'_> {
+        let mut issue = Issue::from_repo(self.repo.as_borrowed());
+
+        issue.params = self
+            .params
+            .iter()
+            .map(|(key, value)| (key.as_str(), Cow::Borrowed(value.as_str())))
+            .collect();
+        issue.options = self.options.clone();
+
+        issue
+    }
+}
+
+/// Writes the same URL returned by `IssueOwned::url`. Refer to the `Display`
+/// implementation for `Issue` for the error case.
+impl fmt::Display for IssueOwned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_issue().fmt(f)
+    }
+}
+
+/// Lists params sorted by key and value. Refer to the `Debug` implementation
+/// for `Issue`.
+impl fmt::Debug for IssueOwned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut params: Vec<(&str, &str)> = self
+            .params
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+
+        params.sort_unstable();
+
+        f.debug_struct("IssueOwned")
+            .field("repository_owner", &self.repo.owner())
+            .field("repository_name", &self.repo.repo())
+            .field("base_url", &self.repo.base_url())
+            .field("params", &params)
+            .field("options", &self.options)
+            .finish()
+    }
+}
+
+/// Clones every field of a borrowed `Issue`, including the options set on
+/// it, so it can be kept beyond the borrow.
+impl<'a> From<&Issue<'a>> for IssueOwned {
+    fn from(issue: &Issue<'a>) -> Self {
+        IssueOwned {
+            repo: issue.repo.to_owned_ref(),
+            params: issue
+                .params
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            options: issue.options.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_same_url_as_issue() {
+        let mut want = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        want.title("Null: The Billion Dollar Mistake");
+        want.body("Null is a flag.");
+        want.template("bug_report.md");
+        want.labels("bug,production,high-severity");
+        want.assignee("EstebanBorai");
+        want.milestone("1");
+        want.projects("1");
+
+        let mut have = IssueOwned::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title(String::from("Null: The Billion Dollar Mistake"));
+        have.body(String::from("Null is a flag."));
+        have.template("bug_report.md");
+        have.labels("bug,production,high-severity");
+        have.assignee("EstebanBorai");
+        have.milestone("1");
+        have.projects("1");
+
+        assert_eq!(have.url().unwrap(), want.url().unwrap());
+    }
+
+    #[test]
+    fn converts_issue_into_owned() {
+        let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        issue.with_base_url("https://github.mycorp.com").unwrap();
+        issue.title("Null: The Billion Dollar Mistake");
+        issue.assignees(&["alice", "bob"]);
+        issue.sort_params();
+
+        let have = IssueOwned::from(&issue);
+
+        assert_eq!(have.url().unwrap(), issue.url().unwrap());
+        assert_eq!(have.as_issue(), issue);
+    }
+
+    #[test]
+    fn replaces_single_valued_params_and_appends_body() {
+        let mut have = IssueOwned::new("github-issue-url", "EstebanBorai").unwrap();
+
+        have.title("a");
+        have.body("Null is a flag.");
+        have.title("b");
+        have.append_body("It represents different situations.");
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=b&body=Null+is+a+flag.%0AIt+represents+different+situations."
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+
+        issue.with_base_url("https://github.mycorp.com").unwrap();
+        issue.title("Null: The Billion Dollar Mistake");
+        issue.labels_slice(&["bug", "production"]).unwrap();
+
+        let json = serde_json::to_string(&issue).unwrap();
+        let have: IssueOwned = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(have.url().unwrap(), issue.url().unwrap());
+        assert_eq!(serde_json::to_string(&have).unwrap(), json);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn return_error_if_deserialized_issue_is_invalid() {
+        let have = serde_json::from_str::<IssueOwned>(
+            r#"{"repository_name":"github-issue-url","repository_owner":"-EstebanBorai","params":[]}"#,
+        );
+
+        assert!(have.unwrap_err().to_string().contains("-EstebanBorai"));
+
+        let have = serde_json::from_str::<IssueOwned>(
+            r#"{"repository_name":"github-issue-url","repository_owner":"EstebanBorai","params":[],"base_url":"https://github.mycorp.com/api"}"#,
+        );
+
+        assert!(have.is_err());
+
+        let have = serde_json::from_str::<IssueOwned>(
+            r#"{"repository_name":"github-issue-url","repository_owner":"EstebanBorai","params":[["title","a"]]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(have.param_value("title"), Some("a"));
+    }
+
+    #[test]
+    fn return_error_if_repository_is_invalid() {
+        let have = IssueOwned::new(String::new(), "EstebanBorai");
+
+        assert!(matches!(have, Err(Error::EmptyRepositoryName)));
+
+        let have = IssueOwned::new("github-issue-url", String::new());
+
+        assert!(matches!(have, Err(Error::EmptyRepositoryOwner)));
+    }
+}
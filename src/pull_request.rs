@@ -0,0 +1,180 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use url::Url;
+
+use crate::error::{Error, Result};
+use crate::{parsed_url_with_params, url_with_params, RepoRef};
+
+/// GitHub pull request struct with support for prefilling a new pull request.
+///
+/// Targets the compare URL
+/// (`https://github.com/<owner>/<repository>/compare/<base>...<head>`) with
+/// the `expand=1` query param, which opens the pull request form right away.
+///
+/// # Example
+///
+/// ```
+/// use github_issue_url::PullRequest;
+///
+/// let mut have = PullRequest::new("github-issue-url", "EstebanBorai", "main", "feat/gitlab").unwrap();
+///
+/// have.title("feat: support gitlab");
+/// have.body("Closes #1");
+///
+/// let have = have.url().unwrap();
+///
+/// assert_eq!(have, "https://github.com/EstebanBorai/github-issue-url/compare/main...feat/gitlab?expand=1&title=feat%3A+support+gitlab&body=Closes+%231");
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct PullRequest<'a> {
+    repo: RepoRef<'a>,
+    base: &'a str,
+    head: &'a str,
+    params: Vec<(&'a str, Cow<'a, str>)>,
+}
+
+impl<'a> PullRequest<'a> {
+    /// Creates a `PullRequest` to merge the `head` branch into the `base`
+    /// branch.
+    ///
+    /// Returns `Error::EmptyBranchName` if any of the branch names is empty.
+    pub fn new(
+        repository_name: &'a str,
+        repository_owner: &'a str,
+        base: &'a str,
+        head: &'a str,
+    ) -> Result<Self> {
+        PullRequest::from_repo(RepoRef::new(repository_owner, repository_name)?, base, head)
+    }
+
+    /// Creates a `PullRequest` for `repo`. Refer to `RepoRef::pull_request`.
+    pub(crate) fn from_repo(repo: RepoRef<'a>, base: &'a str, head: &'a str) -> Result<Self> {
+        if base.is_empty() || head.is_empty() {
+            return Err(Error::EmptyBranchName);
+        }
+
+        Ok(PullRequest {
+            repo,
+            base,
+            head,
+            params: vec![("expand", "1".into())],
+        })
+    }
+
+    /// Prefilled pull request body content, replacing any body previously
+    /// set
+    pub fn body(&mut self, body: &'a str) {
+        self.set_param("body", body);
+    }
+
+    /// Prefilled pull request title, replacing any title previously set
+    pub fn title(&mut self, title: &'a str) {
+        self.set_param("title", title);
+    }
+
+    /// Host to build the pull request URL for. Refer to
+    /// `Issue::with_base_url`.
+    pub fn with_base_url(&mut self, base_url: &str) -> Result<()> {
+        self.repo.with_base_url(base_url)
+    }
+
+    #[must_use = "building the URL has no effect unless it is used"]
+    pub fn url(&self) -> Result<String> {
+        Ok(url_with_params(self.repository_url(), &self.params))
+    }
+
+    /// Builds the pull request URL as a `url::Url`. Refer to
+    /// `Issue::url_parsed`.
+    pub fn url_parsed(&self) -> Result<Url> {
+        Ok(parsed_url_with_params(
+            self.repo.parsed_pull_url(self.base, self.head),
+            &self.params,
+        ))
+    }
+
+    /// The compare URL without query params
+    fn repository_url(&self) -> String {
+        self.repo.new_pull_url(self.base, self.head)
+    }
+
+    fn set_param(&mut self, key: &'a str, value: &'a str) {
+        match self.params.iter_mut().find(|(param, _)| *param == key) {
+            Some((_, current)) => *current = value.into(),
+            None => self.params.push((key, value.into())),
+        }
+    }
+}
+
+/// Writes the same URL returned by `PullRequest::url`, falling back to the
+/// compare URL without query params if building the URL fails.
+impl<'a> fmt::Display for PullRequest<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.url() {
+            Ok(url) => write!(f, "{}", url),
+            Err(_) => write!(f, "{}", self.repository_url()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_pull_request_url() {
+        let mut have =
+            PullRequest::new("github-issue-url", "EstebanBorai", "main", "fix/labels").unwrap();
+
+        have.with_base_url("https://github.mycorp.com").unwrap();
+        have.title("fix: encode labels");
+
+        let have = have.url().unwrap();
+
+        assert_eq!(
+            have,
+            "https://github.mycorp.com/EstebanBorai/github-issue-url/compare/main...fix/labels?expand=1&title=fix%3A+encode+labels"
+        );
+    }
+
+    #[test]
+    fn replace_title_and_body() {
+        let mut have =
+            PullRequest::new("github-issue-url", "EstebanBorai", "main", "fix/labels").unwrap();
+
+        have.title("fix: labels");
+        have.body("Closes #1");
+        have.title("fix: encode labels");
+        have.body("Closes #2");
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/compare/main...fix/labels?expand=1&title=fix%3A+encode+labels&body=Closes+%232"
+        );
+    }
+
+    #[test]
+    fn percent_encode_branch_names() {
+        let have = PullRequest::new("github-issue-url", "EstebanBorai", "main", "fix/#1").unwrap();
+
+        assert_eq!(
+            have.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/compare/main...fix/%231?expand=1"
+        );
+    }
+
+    #[test]
+    fn return_error_if_branch_is_empty() {
+        assert!(matches!(
+            PullRequest::new("github-issue-url", "EstebanBorai", "", "fix/labels"),
+            Err(Error::EmptyBranchName)
+        ));
+        assert!(matches!(
+            PullRequest::new("github-issue-url", "EstebanBorai", "main", ""),
+            Err(Error::EmptyBranchName)
+        ));
+    }
+}
@@ -0,0 +1,93 @@
+/// Repository referenced by a git remote URL
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Remote<'a> {
+    pub(crate) host: &'a str,
+    pub(crate) owner: &'a str,
+    pub(crate) repository: &'a str,
+}
+
+/// Parses a git remote URL in either SSH (`git@github.com:owner/repo.git`,
+/// `ssh://git@github.com/owner/repo.git`) or HTTPS
+/// (`https://github.com/owner/repo.git`) form.
+pub(crate) fn parse_remote(remote: &str) -> Option<Remote<'_>> {
+    let (host, path) = if let Some(rest) = remote
+        .strip_prefix("https://")
+        .or_else(|| remote.strip_prefix("http://"))
+        .or_else(|| remote.strip_prefix("ssh://"))
+    {
+        let rest = rest.split('@').next_back()?;
+        let (host, path) = rest.split_once('/')?;
+
+        (host.split(':').next()?, path)
+    } else {
+        let (user_host, path) = remote.split_once(':')?;
+
+        (user_host.split('@').next_back()?, path)
+    };
+
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let (owner, repository) = path.split_once('/')?;
+
+    if host.is_empty() || owner.is_empty() || repository.is_empty() || repository.contains('/') {
+        return None;
+    }
+
+    Some(Remote {
+        host,
+        owner,
+        repository,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WANT: Remote<'static> = Remote {
+        host: "github.com",
+        owner: "EstebanBorai",
+        repository: "github-issue-url",
+    };
+
+    #[test]
+    fn parses_ssh_remotes() {
+        assert_eq!(
+            parse_remote("git@github.com:EstebanBorai/github-issue-url.git"),
+            Some(WANT)
+        );
+        assert_eq!(
+            parse_remote("ssh://git@github.com/EstebanBorai/github-issue-url.git"),
+            Some(WANT)
+        );
+    }
+
+    #[test]
+    fn parses_https_remotes() {
+        assert_eq!(
+            parse_remote("https://github.com/EstebanBorai/github-issue-url.git"),
+            Some(WANT)
+        );
+        assert_eq!(
+            parse_remote("https://github.com/EstebanBorai/github-issue-url"),
+            Some(WANT)
+        );
+        assert_eq!(
+            parse_remote("https://github.mycorp.com/EstebanBorai/github-issue-url/"),
+            Some(Remote {
+                host: "github.mycorp.com",
+                ..WANT
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_remotes() {
+        assert_eq!(parse_remote("/home/esteban/github-issue-url"), None);
+        assert_eq!(parse_remote("https://github.com/EstebanBorai"), None);
+        assert_eq!(
+            parse_remote("https://gitlab.com/group/subgroup/github-issue-url"),
+            None
+        );
+    }
+}
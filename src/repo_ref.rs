@@ -0,0 +1,338 @@
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+use core::mem;
+
+use url::Url;
+
+#[cfg(feature = "serde")]
+use crate::error::Error;
+use crate::error::Result;
+use crate::{
+    parse_base_url, parsed_repository_url, repository_url, validate_repository,
+    write_repository_url, Discussion, Issue, PullRequest,
+};
+
+/// GitHub repository, identified by its owner and name, and the host it is
+/// served from.
+///
+/// Composed by `Issue`, `IssueOwned`, `PullRequest` and `Discussion` to
+/// build their URLs, and useful as a reusable handle to create any of them
+/// for the same repository.
+///
+/// Owner and name accept any `Into<Cow<'a, str>>`, same as `Issue` text
+/// setters.
+///
+/// With the `serde` feature enabled, the owner and name are serialized as
+/// `repository_owner` and `repository_name`, next to `base_url`. They are
+/// validated same as with `RepoRef::new` when deserializing.
+///
+/// # Example
+///
+/// ```
+/// use github_issue_url::RepoRef;
+///
+/// let repo = RepoRef::new("EstebanBorai", "github-issue-url").unwrap();
+/// let mut issue = repo.issue();
+///
+/// issue.title("Null: The Billion Dollar Mistake");
+///
+/// assert_eq!(
+///     repo.new_issue_url(),
+///     "https://github.com/EstebanBorai/github-issue-url/issues/new"
+/// );
+/// assert_eq!(
+///     issue.url().unwrap(),
+///     "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake"
+/// );
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawRepoRef<'a>"))]
+pub struct RepoRef<'a> {
+    #[cfg_attr(feature = "serde", serde(rename = "repository_owner"))]
+    owner: Cow<'a, str>,
+    #[cfg_attr(feature = "serde", serde(rename = "repository_name"))]
+    repo: Cow<'a, str>,
+    base_url: Option<Url>,
+    /// Cached "New Issue" URL, rebuilt whenever the owner, name or base URL
+    /// change so `Issue::try_url` can borrow it
+    #[cfg_attr(feature = "serde", serde(skip))]
+    new_issue_url: Cow<'a, str>,
+}
+
+/// `RepoRef` as deserialized, before being validated
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawRepoRef<'a> {
+    repository_owner: Cow<'a, str>,
+    repository_name: Cow<'a, str>,
+    #[serde(default)]
+    base_url: Option<Url>,
+}
+
+#[cfg(feature = "serde")]
+impl<'a> core::convert::TryFrom<RawRepoRef<'a>> for RepoRef<'a> {
+    type Error = Error;
+
+    fn try_from(raw: RawRepoRef<'a>) -> Result<Self> {
+        let mut repo = RepoRef::new(raw.repository_owner, raw.repository_name)?;
+
+        if let Some(base_url) = raw.base_url {
+            repo.with_base_url(base_url.as_str())?;
+        }
+
+        Ok(repo)
+    }
+}
+
+impl<'a> RepoRef<'a> {
+    /// Returns the same errors as `Issue::new` if the repository owner or
+    /// name is empty or doesn't follow GitHub naming rules.
+    pub fn new(owner: impl Into<Cow<'a, str>>, repo: impl Into<Cow<'a, str>>) -> Result<Self> {
+        let repo = RepoRef::new_unchecked(owner, repo);
+
+        repo.validate()?;
+
+        Ok(repo)
+    }
+
+    /// Creates a `RepoRef` without validating the owner and name. Refer to
+    /// `Issue::new_unchecked`.
+    pub(crate) fn new_unchecked(
+        owner: impl Into<Cow<'a, str>>,
+        repo: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        let mut repo = RepoRef {
+            owner: owner.into(),
+            repo: repo.into(),
+            base_url: None,
+            new_issue_url: Cow::Borrowed(""),
+        };
+
+        repo.cache_new_issue_url();
+        repo
+    }
+
+    /// Checks the owner and name follow GitHub naming rules
+    pub(crate) fn validate(&self) -> Result<()> {
+        validate_repository(&self.repo, &self.owner)
+    }
+
+    /// Host serving the repository. Refer to `Issue::with_base_url`.
+    pub fn with_base_url(&mut self, base_url: &str) -> Result<()> {
+        self.base_url = Some(parse_base_url(base_url)?);
+        self.cache_new_issue_url();
+
+        Ok(())
+    }
+
+    /// Points to another repository served from the same host, leaving the
+    /// `RepoRef` untouched if the owner or name are invalid
+    pub(crate) fn reset_to(
+        &mut self,
+        owner: impl Into<Cow<'a, str>>,
+        repo: impl Into<Cow<'a, str>>,
+    ) -> Result<()> {
+        let (owner, repo) = (owner.into(), repo.into());
+
+        validate_repository(&repo, &owner)?;
+        self.owner = owner;
+        self.repo = repo;
+        self.cache_new_issue_url();
+
+        Ok(())
+    }
+
+    /// The repository owner, user or organization
+    pub fn owner(&self) -> &str {
+        &self.owner
+    }
+
+    /// The repository name
+    pub fn repo(&self) -> &str {
+        &self.repo
+    }
+
+    /// The host set with `RepoRef::with_base_url`, if any
+    pub fn base_url(&self) -> Option<&Url> {
+        self.base_url.as_ref()
+    }
+
+    /// The "New Issue" URL without query params
+    pub fn new_issue_url(&self) -> &str {
+        &self.new_issue_url
+    }
+
+    /// The compare URL to open a pull request merging `head` into `base`,
+    /// without query params
+    pub fn new_pull_url(&self, base: &str, head: &str) -> String {
+        self.url(&pull_path(base, head))
+    }
+
+    /// The "New Discussion" URL without query params
+    pub fn new_discussion_url(&self) -> String {
+        self.url("discussions/new")
+    }
+
+    /// Creates an `Issue` for the repository, using the same base URL
+    pub fn issue(&self) -> Issue<'a> {
+        Issue::from_repo(self.clone())
+    }
+
+    /// Creates a `PullRequest` for the repository, using the same base URL.
+    /// Refer to `PullRequest::new`.
+    pub fn pull_request(&self, base: &'a str, head: &'a str) -> Result<PullRequest<'a>> {
+        PullRequest::from_repo(self.clone(), base, head)
+    }
+
+    /// Creates a `Discussion` for the repository, using the same base URL
+    pub fn discussion(&self) -> Discussion<'a> {
+        Discussion::from_repo(self.clone())
+    }
+
+    /// Borrows a `RepoRef` out of this one, cloning only the base URL
+    pub(crate) fn as_borrowed(&self) -> RepoRef<'_> {
+        RepoRef {
+            owner: Cow::Borrowed(&self.owner),
+            repo: Cow::Borrowed(&self.repo),
+            base_url: self.base_url.clone(),
+            new_issue_url: Cow::Borrowed(&self.new_issue_url),
+        }
+    }
+
+    /// Clones the owner and name so the `RepoRef` can be kept beyond the
+    /// borrow
+    pub(crate) fn to_owned_ref(&self) -> RepoRef<'static> {
+        RepoRef {
+            owner: Cow::Owned(self.owner.clone().into_owned()),
+            repo: Cow::Owned(self.repo.clone().into_owned()),
+            base_url: self.base_url.clone(),
+            new_issue_url: Cow::Owned(self.new_issue_url.clone().into_owned()),
+        }
+    }
+
+    /// Rebuilds the cached "New Issue" URL, reusing its buffer if owned
+    fn cache_new_issue_url(&mut self) {
+        let mut url = mem::take(&mut self.new_issue_url).into_owned();
+
+        url.clear();
+        self.write_url(&mut url, "issues/new");
+        self.new_issue_url = Cow::Owned(url);
+    }
+
+    /// URL of `path` within the repository
+    pub(crate) fn url(&self, path: &str) -> String {
+        repository_url(self.base_url.as_ref(), &self.owner, &self.repo, path)
+    }
+
+    /// Writes the URL of `path` within the repository into `buf`
+    pub(crate) fn write_url(&self, buf: &mut String, path: &str) {
+        write_repository_url(buf, self.base_url.as_ref(), &self.owner, &self.repo, path);
+    }
+
+    /// Same as `RepoRef::new_pull_url` as a `url::Url`
+    pub(crate) fn parsed_pull_url(&self, base: &str, head: &str) -> Url {
+        self.parsed_url(&pull_path(base, head))
+    }
+
+    /// Same as `RepoRef::new_discussion_url` as a `url::Url`
+    pub(crate) fn parsed_discussion_url(&self) -> Url {
+        self.parsed_url("discussions/new")
+    }
+
+    /// Same as `RepoRef::url` as a `url::Url`
+    pub(crate) fn parsed_url(&self, path: &str) -> Url {
+        parsed_repository_url(self.base_url.as_ref(), &self.owner, &self.repo, path)
+    }
+}
+
+impl<'a> fmt::Debug for RepoRef<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RepoRef")
+            .field("owner", &self.owner)
+            .field("repo", &self.repo)
+            .field("base_url", &self.base_url)
+            .finish()
+    }
+}
+
+/// Path of the compare page merging `head` into `base`
+fn pull_path(base: &str, head: &str) -> String {
+    format!("compare/{}...{}", base, head)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_repository_urls() {
+        let mut have = RepoRef::new("EstebanBorai", "github-issue-url").unwrap();
+
+        have.with_base_url("https://github.mycorp.com").unwrap();
+
+        assert_eq!(
+            have.issue().url().unwrap(),
+            "https://github.mycorp.com/EstebanBorai/github-issue-url/issues/new"
+        );
+        assert_eq!(
+            have.pull_request("main", "fix/labels").unwrap().url().unwrap(),
+            "https://github.mycorp.com/EstebanBorai/github-issue-url/compare/main...fix/labels?expand=1"
+        );
+        assert_eq!(
+            have.discussion().url().unwrap(),
+            "https://github.mycorp.com/EstebanBorai/github-issue-url/discussions/new"
+        );
+    }
+
+    #[test]
+    fn share_repository_with_issues() {
+        let mut repo = RepoRef::new(String::from("EstebanBorai"), "github-issue-url").unwrap();
+
+        repo.with_base_url("https://github.mycorp.com").unwrap();
+
+        let mut issue = repo.issue();
+
+        issue
+            .reset_to("github-issue-url-cli", "EstebanBorai")
+            .unwrap();
+
+        assert_eq!(
+            issue.url().unwrap(),
+            "https://github.mycorp.com/EstebanBorai/github-issue-url-cli/issues/new"
+        );
+        assert!(matches!(
+            issue.reset_to("github-issue-url", "-EstebanBorai"),
+            Err(crate::error::Error::InvalidRepositoryOwner(_))
+        ));
+        assert_eq!(repo.owner(), "EstebanBorai");
+    }
+
+    #[test]
+    fn build_same_parsed_urls_as_urls() {
+        let repo = RepoRef::new("EstebanBorai", "github-issue-url").unwrap();
+        let mut issue = repo.issue();
+        let mut pull_request = repo.pull_request("main", "fix/a b").unwrap();
+        let mut discussion = repo.discussion();
+
+        issue.title("Null pointer (again)");
+        pull_request.title("fix: null pointer (again)");
+        discussion.title("Null pointer (again)");
+
+        assert_eq!(issue.url_parsed().unwrap().as_str(), issue.url().unwrap());
+        assert_eq!(
+            pull_request.url_parsed().unwrap().as_str(),
+            pull_request.url().unwrap()
+        );
+        assert_eq!(
+            discussion.url_parsed().unwrap().as_str(),
+            discussion.url().unwrap()
+        );
+        assert_eq!(
+            discussion.url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/discussions/new?title=Null+pointer+%28again%29"
+        );
+    }
+}
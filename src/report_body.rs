@@ -0,0 +1,102 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Issue body made of markdown sections, each one under a `## heading`.
+///
+/// Sections render in the order they were added and sections with empty or
+/// whitespace only text are omitted.
+///
+/// # Example
+///
+/// ```
+/// use github_issue_url::{Issue, ReportBody};
+///
+/// let body = ReportBody::new()
+///     .section("Summary", "Panics on startup")
+///     .section("Steps to Reproduce", "1. Run `my-app`")
+///     .section("Expected", "")
+///     .section("Actual", "thread 'main' panicked")
+///     .build();
+///
+/// assert_eq!(
+///     body,
+///     "## Summary\n\nPanics on startup\n\n## Steps to Reproduce\n\n1. Run `my-app`\n\n## Actual\n\nthread 'main' panicked"
+/// );
+///
+/// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+///
+/// issue.body(body);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReportBody<'a> {
+    sections: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> ReportBody<'a> {
+    pub fn new() -> Self {
+        ReportBody::default()
+    }
+
+    /// Adds a section with `text` under a `## heading`
+    pub fn section(
+        &mut self,
+        heading: impl Into<Cow<'a, str>>,
+        text: impl Into<Cow<'a, str>>,
+    ) -> &mut Self {
+        self.sections.push((heading.into(), text.into()));
+        self
+    }
+
+    /// Renders every non-empty section, separated by a blank line and
+    /// without a trailing newline.
+    pub fn build(&self) -> String {
+        let mut body = String::new();
+
+        for (heading, text) in &self.sections {
+            let text = text.trim_end();
+
+            if text.trim_start().is_empty() {
+                continue;
+            }
+
+            if !body.is_empty() {
+                body.push_str("\n\n");
+            }
+
+            body.push_str("## ");
+            body.push_str(heading);
+            body.push_str("\n\n");
+            body.push_str(text);
+        }
+
+        body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_sections_in_order() {
+        let have = ReportBody::new()
+            .section("Expected", "No panic\n")
+            .section(String::from("Actual"), String::from("Panic"))
+            .build();
+
+        assert_eq!(have, "## Expected\n\nNo panic\n\n## Actual\n\nPanic");
+    }
+
+    #[test]
+    fn omits_empty_sections() {
+        let have = ReportBody::new()
+            .section("Summary", " \n")
+            .section("Environment", "- OS: linux")
+            .section("Actual", "")
+            .build();
+
+        assert_eq!(have, "## Environment\n\n- OS: linux");
+        assert_eq!(ReportBody::new().build(), "");
+    }
+}
@@ -0,0 +1,84 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Markdown task list to include in an issue body.
+///
+/// Items render as `- [ ] text` or `- [x] text` when checked, one per line.
+///
+/// # Example
+///
+/// ```
+/// use github_issue_url::TaskList;
+///
+/// let tasks = TaskList::new()
+///     .checked_item("Reproduce the bug")
+///     .item("Write a failing test")
+///     .item("Fix the bug")
+///     .render();
+///
+/// assert_eq!(
+///     tasks,
+///     "- [x] Reproduce the bug\n- [ ] Write a failing test\n- [ ] Fix the bug"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskList<'a> {
+    items: Vec<(bool, Cow<'a, str>)>,
+}
+
+impl<'a> TaskList<'a> {
+    pub fn new() -> Self {
+        TaskList::default()
+    }
+
+    /// Adds an unchecked item (`- [ ] text`)
+    pub fn item(&mut self, text: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.items.push((false, text.into()));
+        self
+    }
+
+    /// Adds a checked item (`- [x] text`)
+    pub fn checked_item(&mut self, text: impl Into<Cow<'a, str>>) -> &mut Self {
+        self.items.push((true, text.into()));
+        self
+    }
+
+    /// Renders every item in the order they were added, separated by `\n`
+    /// and without a trailing newline.
+    pub fn render(&self) -> String {
+        let mut list = String::new();
+
+        for (index, (checked, text)) in self.items.iter().enumerate() {
+            if index > 0 {
+                list.push('\n');
+            }
+
+            list.push_str(if *checked { "- [x] " } else { "- [ ] " });
+            list.push_str(text);
+        }
+
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_task_list() {
+        let have = TaskList::new()
+            .item("Step 1")
+            .checked_item(String::from("Step 2"))
+            .item("Step 3")
+            .render();
+
+        assert_eq!(have, "- [ ] Step 1\n- [x] Step 2\n- [ ] Step 3");
+    }
+
+    #[test]
+    fn renders_empty_task_list() {
+        assert_eq!(TaskList::new().render(), "");
+    }
+}
@@ -0,0 +1,238 @@
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::error::{Error, Result};
+
+/// Issue template file names, set with `Issue::template_kind`.
+///
+/// `Template::BugReport` and `Template::FeatureRequest` match the file names
+/// GitHub uses for the templates it suggests when setting up issue templates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Template<'a> {
+    /// `bug_report.md`
+    BugReport,
+    /// `feature_request.md`
+    FeatureRequest,
+    /// Any other template file name, such as `bug_report.yml`
+    Custom(&'a str),
+}
+
+impl<'a> Template<'a> {
+    /// File name of the template in `.github/ISSUE_TEMPLATE`
+    pub fn file_name(&self) -> &'a str {
+        match self {
+            Template::BugReport => "bug_report.md",
+            Template::FeatureRequest => "feature_request.md",
+            Template::Custom(file_name) => file_name,
+        }
+    }
+}
+
+/// Issue body template with named placeholders.
+///
+/// Placeholders are written as `{name}` and replaced by the values provided
+/// with `BodyTemplate::set`. Use `{{` and `}}` to write literal braces.
+///
+/// # Example
+///
+/// ```
+/// use github_issue_url::BodyTemplate;
+///
+/// let body = BodyTemplate::new("OS: {os}\nVersion: {version}\n\n{description}")
+///     .set("os", "linux")
+///     .set("version", "1.2.3")
+///     .set("description", "Panics on startup")
+///     .render()
+///     .unwrap();
+///
+/// assert_eq!(body, "OS: linux\nVersion: 1.2.3\n\nPanics on startup");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BodyTemplate<'a> {
+    template: &'a str,
+    variables: Vec<(&'a str, Cow<'a, str>)>,
+}
+
+impl<'a> BodyTemplate<'a> {
+    pub fn new(template: &'a str) -> Self {
+        BodyTemplate {
+            template,
+            variables: Vec::new(),
+        }
+    }
+
+    /// Sets the value to replace the `{name}` placeholder with, overriding any
+    /// value previously set for the same placeholder.
+    pub fn set(&mut self, name: &'a str, value: impl Into<Cow<'a, str>>) -> &mut Self {
+        let value = value.into();
+
+        match self.variables.iter_mut().find(|(key, _)| *key == name) {
+            Some((_, current)) => *current = value,
+            None => self.variables.push((name, value)),
+        }
+
+        self
+    }
+
+    /// Replaces every placeholder with its value.
+    ///
+    /// Returns `Error::MissingTemplateVariable` with the placeholder name if
+    /// any placeholder has no value set. Braces not surrounding a name
+    /// without whitespace, such as `{}` or `{ }`, are kept as is.
+    pub fn render(&self) -> Result<String> {
+        let mut body = String::with_capacity(self.template.len());
+        let mut rest = self.template;
+
+        while let Some(index) = rest.find(['{', '}']) {
+            body.push_str(&rest[..index]);
+
+            let tail = &rest[index..];
+
+            if tail.starts_with("{{") || tail.starts_with("}}") {
+                body.push_str(&tail[..1]);
+                rest = &tail[2..];
+                continue;
+            }
+
+            if let Some(tail) = tail.strip_prefix('}') {
+                body.push('}');
+                rest = tail;
+                continue;
+            }
+
+            let name = tail[1..]
+                .find(['{', '}'])
+                .filter(|end| tail.as_bytes()[end + 1] == b'}')
+                .map(|end| &tail[1..=end])
+                .filter(|name| is_placeholder_name(name));
+
+            match name {
+                Some(name) => {
+                    let value = self
+                        .variables
+                        .iter()
+                        .find(|(key, _)| *key == name)
+                        .map(|(_, value)| value)
+                        .ok_or_else(|| Error::MissingTemplateVariable(name.to_string()))?;
+
+                    body.push_str(value);
+                    rest = &tail[name.len() + 2..];
+                }
+                None => {
+                    body.push('{');
+                    rest = &tail[1..];
+                }
+            }
+        }
+
+        body.push_str(rest);
+
+        Ok(body)
+    }
+}
+
+/// Replaces `${NAME}` occurrences in `template` with the value returned by
+/// `var` for `NAME`, and `$$` with a literal `$`.
+///
+/// Returns `Error::MissingEnvVar` with the variable name if `var` returns
+/// `None`. Any other `$` is kept as is.
+#[cfg(feature = "std")]
+pub(crate) fn expand_vars(template: &str, var: impl Fn(&str) -> Option<String>) -> Result<String> {
+    let mut body = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(index) = rest.find('$') {
+        body.push_str(&rest[..index]);
+
+        let tail = &rest[index..];
+
+        if let Some(tail) = tail.strip_prefix("$$") {
+            body.push('$');
+            rest = tail;
+            continue;
+        }
+
+        let name = tail
+            .strip_prefix("${")
+            .and_then(|tail| tail.find('}').map(|end| &tail[..end]))
+            .filter(|name| is_placeholder_name(name));
+
+        match name {
+            Some(name) => {
+                let value = var(name).ok_or_else(|| Error::MissingEnvVar(name.to_string()))?;
+
+                body.push_str(&value);
+                rest = &tail[name.len() + 3..];
+            }
+            None => {
+                body.push('$');
+                rest = &tail[1..];
+            }
+        }
+    }
+
+    body.push_str(rest);
+
+    Ok(body)
+}
+
+/// Placeholder names are non-empty and have no whitespace
+fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty() && !name.contains(char::is_whitespace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_placeholders() {
+        let have = BodyTemplate::new("OS: {os}\nVersion: {version}\n\n{os} again")
+            .set("os", "linux")
+            .set("version", String::from("1.2.3"))
+            .render()
+            .unwrap();
+
+        assert_eq!(have, "OS: linux\nVersion: 1.2.3\n\nlinux again");
+    }
+
+    #[test]
+    fn renders_escaped_braces() {
+        let have = BodyTemplate::new("fn {name}() {{ }} {} { }")
+            .set("name", "main")
+            .render()
+            .unwrap();
+
+        assert_eq!(have, "fn main() { } {} { }");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn expands_vars() {
+        let var = |name: &str| match name {
+            "OS" => Some(String::from("linux")),
+            "VERSION" => Some(String::from("1.2.3")),
+            _ => None,
+        };
+        let have = expand_vars("OS: ${OS} ${VERSION}\nCost: $$5 $ ${} ${OS", var).unwrap();
+
+        assert_eq!(have, "OS: linux 1.2.3\nCost: $5 $ ${} ${OS");
+        assert!(matches!(
+            expand_vars("${OS} ${CI}", var),
+            Err(Error::MissingEnvVar(name)) if name == "CI"
+        ));
+    }
+
+    #[test]
+    fn return_error_if_variable_is_missing() {
+        let have = BodyTemplate::new("OS: {os}\nVersion: {version}")
+            .set("os", "linux")
+            .render();
+
+        assert!(matches!(
+            have,
+            Err(Error::MissingTemplateVariable(name)) if name == "version"
+        ));
+    }
+}
@@ -0,0 +1,205 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::error::{Error, Result};
+
+/// Triage state of an issue, rendered as labels with `TriageLabels`.
+///
+/// Fields left as `None` produce no label.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Triage<'a> {
+    pub priority: Option<&'a str>,
+    pub severity: Option<&'a str>,
+    pub area: Option<&'a str>,
+}
+
+impl<'a> Triage<'a> {
+    /// Renders the labels with the default `TriageLabels` convention, such as
+    /// `priority:high,severity:critical,area:networking`. Refer to
+    /// `TriageLabels::render` for the error case.
+    pub fn labels(&self) -> Result<String> {
+        TriageLabels::new().render(self)
+    }
+}
+
+/// Label convention used to render a `Triage` as the comma separated labels
+/// expected by `Issue::labels`.
+///
+/// Each label is made of a prefix, the separator and the value. Prefixes
+/// default to `priority`, `severity` and `area` and the separator defaults
+/// to `:`.
+///
+/// # Example
+///
+/// ```
+/// use github_issue_url::{Issue, Triage, TriageLabels};
+///
+/// let triage = Triage {
+///     priority: Some("high"),
+///     severity: Some("critical"),
+///     area: Some("networking"),
+/// };
+/// let labels = TriageLabels::new()
+///     .priority("P")
+///     .area("team")
+///     .separator("/")
+///     .render(&triage)
+///     .unwrap();
+///
+/// assert_eq!(labels, "P/high,severity/critical,team/networking");
+///
+/// let mut issue = Issue::new("github-issue-url", "EstebanBorai").unwrap();
+///
+/// issue.labels(labels);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TriageLabels<'a> {
+    priority: &'a str,
+    severity: &'a str,
+    area: &'a str,
+    separator: &'a str,
+}
+
+impl<'a> TriageLabels<'a> {
+    pub fn new() -> Self {
+        TriageLabels::default()
+    }
+
+    /// Prefix of the `Triage::priority` label
+    pub fn priority(&mut self, prefix: &'a str) -> &mut Self {
+        self.priority = prefix;
+        self
+    }
+
+    /// Prefix of the `Triage::severity` label
+    pub fn severity(&mut self, prefix: &'a str) -> &mut Self {
+        self.severity = prefix;
+        self
+    }
+
+    /// Prefix of the `Triage::area` label
+    pub fn area(&mut self, prefix: &'a str) -> &mut Self {
+        self.area = prefix;
+        self
+    }
+
+    /// Separator between each prefix and its value
+    pub fn separator(&mut self, separator: &'a str) -> &mut Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Renders the labels set on `triage`, in priority, severity and area
+    /// order, joined by `,`.
+    ///
+    /// Returns `Error::InvalidTriageLabel` with the first label whose prefix
+    /// or value is empty, or which contains a comma, as it would break the
+    /// comma separated labels.
+    pub fn render(&self, triage: &Triage) -> Result<String> {
+        let mut labels = Vec::new();
+
+        for (prefix, value) in [
+            (self.priority, triage.priority),
+            (self.severity, triage.severity),
+            (self.area, triage.area),
+        ] {
+            let value = match value {
+                Some(value) => value,
+                None => continue,
+            };
+            let label = format!("{}{}{}", prefix, self.separator, value);
+
+            if prefix.is_empty() || value.is_empty() || label.contains(',') {
+                return Err(Error::InvalidTriageLabel(label));
+            }
+
+            labels.push(label);
+        }
+
+        Ok(labels.join(","))
+    }
+}
+
+impl<'a> Default for TriageLabels<'a> {
+    fn default() -> Self {
+        TriageLabels {
+            priority: "priority",
+            severity: "severity",
+            area: "area",
+            separator: ":",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_default_convention() {
+        let triage = Triage {
+            priority: Some("high"),
+            severity: Some("critical"),
+            area: Some("networking"),
+        };
+
+        assert_eq!(
+            triage.labels().unwrap(),
+            "priority:high,severity:critical,area:networking"
+        );
+        assert_eq!(Triage::default().labels().unwrap(), "");
+    }
+
+    #[test]
+    fn renders_custom_convention_skipping_unset_fields() {
+        let triage = Triage {
+            severity: Some("minor"),
+            area: Some("cli"),
+            ..Triage::default()
+        };
+        let have = TriageLabels::new()
+            .severity("sev")
+            .separator("-")
+            .render(&triage)
+            .unwrap();
+
+        assert_eq!(have, "sev-minor,area-cli");
+    }
+
+    #[test]
+    fn return_error_if_label_would_break_labels() {
+        let triage = Triage {
+            priority: Some("high"),
+            area: Some("cli,networking"),
+            ..Triage::default()
+        };
+
+        assert!(matches!(
+            triage.labels(),
+            Err(Error::InvalidTriageLabel(label)) if label == "area:cli,networking"
+        ));
+
+        let triage = Triage {
+            priority: Some("high"),
+            ..Triage::default()
+        };
+
+        assert!(matches!(
+            TriageLabels::new().separator(",").render(&triage),
+            Err(Error::InvalidTriageLabel(_))
+        ));
+        assert!(matches!(
+            TriageLabels::new().priority("").render(&triage),
+            Err(Error::InvalidTriageLabel(_))
+        ));
+        assert!(matches!(
+            Triage {
+                severity: Some(""),
+                ..Triage::default()
+            }
+            .labels(),
+            Err(Error::InvalidTriageLabel(_))
+        ));
+    }
+}
@@ -0,0 +1,95 @@
+//! `wasm-bindgen` binding to build issue URLs from JavaScript.
+//!
+//! ```js
+//! import { Issue } from "github-issue-url";
+//!
+//! const issue = Issue.newIssue("github-issue-url", "EstebanBorai");
+//!
+//! issue.setTitle("Null: The Billion Dollar Mistake");
+//! issue.setBody("Null is a flag.");
+//! issue.setLabels("bug,production");
+//!
+//! window.open(issue.buildUrl());
+//! ```
+use alloc::string::String;
+
+use wasm_bindgen::prelude::*;
+
+use crate::IssueOwned;
+
+/// `IssueOwned` exposed to JavaScript as `Issue`
+#[wasm_bindgen(js_name = Issue)]
+pub struct WasmIssue {
+    issue: IssueOwned,
+}
+
+#[wasm_bindgen(js_class = Issue)]
+impl WasmIssue {
+    /// Throws if the repository name or owner is not valid
+    #[wasm_bindgen(js_name = newIssue)]
+    pub fn new_issue(repository_name: &str, repository_owner: &str) -> Result<WasmIssue, JsError> {
+        Ok(WasmIssue {
+            issue: IssueOwned::new(repository_name, repository_owner)?,
+        })
+    }
+
+    #[wasm_bindgen(js_name = setTitle)]
+    pub fn set_title(&mut self, title: &str) {
+        self.issue.title(title);
+    }
+
+    #[wasm_bindgen(js_name = setBody)]
+    pub fn set_body(&mut self, body: &str) {
+        self.issue.body(body);
+    }
+
+    /// Comma separated label names
+    #[wasm_bindgen(js_name = setLabels)]
+    pub fn set_labels(&mut self, labels: &str) {
+        self.issue.labels(labels);
+    }
+
+    #[wasm_bindgen(js_name = setAssignee)]
+    pub fn set_assignee(&mut self, assignee: &str) {
+        self.issue.assignee(assignee);
+    }
+
+    #[wasm_bindgen(js_name = setMilestone)]
+    pub fn set_milestone(&mut self, milestone: &str) {
+        self.issue.milestone(milestone);
+    }
+
+    #[wasm_bindgen(js_name = setTemplate)]
+    pub fn set_template(&mut self, template: &str) {
+        self.issue.template(template);
+    }
+
+    /// Throws if the base URL has a path, query or fragment
+    #[wasm_bindgen(js_name = setBaseUrl)]
+    pub fn set_base_url(&mut self, base_url: &str) -> Result<(), JsError> {
+        Ok(self.issue.with_base_url(base_url)?)
+    }
+
+    #[wasm_bindgen(js_name = buildUrl)]
+    pub fn build_url(&self) -> Result<String, JsError> {
+        Ok(self.issue.url()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_issue_url() {
+        let mut have = WasmIssue::new_issue("github-issue-url", "EstebanBorai").unwrap();
+
+        have.set_title("Null: The Billion Dollar Mistake");
+        have.set_labels("bug,production");
+
+        assert_eq!(
+            have.build_url().unwrap(),
+            "https://github.com/EstebanBorai/github-issue-url/issues/new?title=Null%3A+The+Billion+Dollar+Mistake&labels=bug%2Cproduction"
+        );
+    }
+}